@@ -0,0 +1,103 @@
+// This is free and unencumbered software released into the public domain.
+//
+// Anyone is free to copy, modify, publish, use, compile, sell, or
+// distribute this software, either in source code form or as a compiled
+// binary, for any purpose, commercial or non-commercial, and by any
+// means.
+//
+// In jurisdictions that recognize copyright laws, the author or authors
+// of this software dedicate any and all copyright interest in the
+// software to the public domain. We make this dedication for the benefit
+// of the public at large and to the detriment of our heirs and
+// successors. We intend this dedication to be an overt act of
+// relinquishment in perpetuity of all present and future rights to this
+// software under copyright law.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.
+// IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY CLAIM, DAMAGES OR
+// OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE,
+// ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+//
+// For more information, please refer to <http://unlicense.org>
+
+//! RPC methods serving aggregated chain statistics, under the `stats_*` namespace.
+//!
+//! Watchers and dashboards get the health of the chain in one call with `stats_chainStats`.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{ErrorObject, ErrorObjectOwned},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::traits::Block as BlockT;
+
+pub use chain_stats_rpc_runtime_api::{ChainStats, ChainStatsApi as ChainStatsRuntimeApi};
+
+/// Error code of a failed runtime call.
+const RUNTIME_ERROR: i32 = 1;
+
+/// Aggregated chain statistics.
+#[rpc(client, server)]
+pub trait ChainStatsApi<BlockHash> {
+    /// The statistics at block `at`, or at the best block.
+    #[method(name = "stats_chainStats")]
+    fn chain_stats(&self, at: Option<BlockHash>) -> RpcResult<ChainStats<NumberOrHex>>;
+}
+
+/// Implementation of [`ChainStatsApiServer`], calling into [`ChainStatsRuntimeApi`].
+pub struct ChainStatsRpc<C, Block, Balance> {
+    client: Arc<C>,
+    _marker: PhantomData<(Block, Balance)>,
+}
+
+impl<C, Block, Balance> ChainStatsRpc<C, Block, Balance> {
+    /// Creates the RPC handler on top of `client`.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<C, Block, Balance> ChainStatsApiServer<<Block as BlockT>::Hash>
+    for ChainStatsRpc<C, Block, Balance>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: ChainStatsRuntimeApi<Block, Balance>,
+    Balance: Codec + Into<NumberOrHex> + Send + Sync + 'static,
+{
+    fn chain_stats(&self, at: Option<Block::Hash>) -> RpcResult<ChainStats<NumberOrHex>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let stats = self
+            .client
+            .runtime_api()
+            .chain_stats(at)
+            .map_err(runtime_error)?;
+
+        Ok(ChainStats {
+            total_issuance: stats.total_issuance.into(),
+            accounts: stats.accounts,
+            collators: stats.collators,
+            session_index: stats.session_index,
+        })
+    }
+}
+
+fn runtime_error(err: sp_api::ApiError) -> ErrorObjectOwned {
+    ErrorObject::owned(
+        RUNTIME_ERROR,
+        "Unable to query the chain stats.",
+        Some(err.to_string()),
+    )
+}
@@ -0,0 +1,129 @@
+//! The `network.toml` schema, as read by `pop up parachain` and zombienet.
+//!
+//! Only the part of the schema needed to launch the network is modelled, other keys are ignored so
+//! that the same file keeps working with those tools.
+
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+/// A relay chain and the parachains registered on it.
+#[derive(Debug, Deserialize)]
+pub struct NetworkConfig {
+    /// The relay chain.
+    pub relaychain: RelayChainConfig,
+    /// The parachains, each registered through sudo once the relay chain runs.
+    #[serde(default)]
+    pub parachains: Vec<ParachainConfig>,
+}
+
+/// The relay chain of the network.
+#[derive(Debug, Deserialize)]
+pub struct RelayChainConfig {
+    /// Chain spec of the relay chain, e.g. `rococo-local`.
+    pub chain: String,
+    /// Relay chain binary, unless overridden on the command line.
+    pub default_command: Option<PathBuf>,
+    /// Arguments passed to every relay chain node.
+    #[serde(default)]
+    pub default_args: Vec<String>,
+    /// Relay chain nodes.
+    pub nodes: Vec<NodeConfig>,
+}
+
+/// A parachain of the network.
+#[derive(Debug, Deserialize)]
+pub struct ParachainConfig {
+    /// Para ID of the parachain.
+    pub id: u32,
+    /// Collator binary.
+    pub default_command: Option<PathBuf>,
+    /// Arguments passed to every collator.
+    #[serde(default)]
+    pub default_args: Vec<String>,
+    /// Collators of the parachain.
+    pub collators: Vec<NodeConfig>,
+}
+
+/// A relay chain node or a collator.
+#[derive(Debug, Deserialize)]
+pub struct NodeConfig {
+    /// Name of the node. Relay chain validators are named after the development account whose
+    /// keys they use, e.g. `alice`.
+    pub name: String,
+    /// Whether the relay chain node is a validator.
+    #[serde(default = "default_validator")]
+    pub validator: bool,
+    /// Binary of the node, overriding the default command.
+    pub command: Option<PathBuf>,
+    /// Arguments passed to the node.
+    #[serde(default)]
+    pub args: Vec<String>,
+}
+
+fn default_validator() -> bool {
+    true
+}
+
+impl NetworkConfig {
+    /// Read the network config from `path`.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let config = std::fs::read_to_string(path)
+            .map_err(|e| format!("Error reading `{}`: {}", path.display(), e))?;
+        Self::parse(&config)
+            .map_err(|e| format!("Invalid network config `{}`: {}", path.display(), e))
+    }
+
+    fn parse(config: &str) -> Result<Self, String> {
+        let config: Self = toml::from_str(config).map_err(|e| e.to_string())?;
+        if !config.relaychain.nodes.iter().any(|node| node.validator) {
+            return Err("the relay chain needs at least one validator".into());
+        }
+        if let Some(para) = config
+            .parachains
+            .iter()
+            .find(|para| para.collators.is_empty())
+        {
+            return Err(format!("parachain {} has no collator", para.id));
+        }
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_the_workspace_network_config() {
+        let config = NetworkConfig::parse(include_str!("../../network.toml")).unwrap();
+
+        assert_eq!(config.relaychain.chain, "rococo-local");
+        assert_eq!(config.relaychain.nodes.len(), 2);
+        assert_eq!(config.parachains.len(), 1);
+        let para = &config.parachains[0];
+        assert_eq!(para.id, 2000);
+        assert_eq!(
+            para.default_command.as_deref(),
+            Some(Path::new("./target/release/parachain-template-node"))
+        );
+        assert_eq!(para.collators[0].name, "collator-01");
+    }
+
+    #[test]
+    fn a_parachain_needs_a_collator() {
+        let config = r#"
+            [relaychain]
+            chain = "rococo-local"
+
+            [[relaychain.nodes]]
+            name = "alice"
+
+            [[parachains]]
+            id = 2000
+            collators = []
+        "#;
+
+        assert!(NetworkConfig::parse(config).is_err());
+    }
+}
@@ -0,0 +1,278 @@
+//! Launches a local network from `network.toml`: a relay chain from a given relay chain binary and
+//! the collators of each parachain, which are registered through sudo.
+//!
+//! Chain specs, node keys, session keys and ports are all generated, so the network only needs the
+//! binaries and runs entirely on the local machine. Logs of the nodes are written next to their
+//! databases.
+
+mod config;
+mod node;
+mod registration;
+mod spec;
+
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::Duration,
+};
+
+use clap::Parser;
+use sp_core::{sr25519, Pair};
+
+use config::NetworkConfig;
+use node::{Node, NodeCommand, NodeKey, Ports};
+use registration::RelayPallets;
+use spec::Collator;
+
+/// Relay chain validators, named after the development accounts whose keys they use.
+const DEV_VALIDATORS: &[&str] = &[
+    "alice", "bob", "charlie", "dave", "eve", "ferdie", "one", "two",
+];
+
+/// Launch a local relay chain and parachain network described by a `network.toml`.
+#[derive(Debug, Parser)]
+struct Cli {
+    /// The network config, in the `network.toml` schema of `pop up parachain` and zombienet.
+    #[arg(long, short, default_value = "network.toml")]
+    config: PathBuf,
+
+    /// Relay chain binary, e.g. `polkadot`, overriding `relaychain.default_command`.
+    ///
+    /// The `polkadot-prepare-worker` and `polkadot-execute-worker` binaries must be next to it.
+    #[arg(long)]
+    relay_binary: Option<PathBuf>,
+
+    /// Directory holding the chain specs, databases and logs of the network.
+    ///
+    /// A temporary directory is used and removed at teardown by default.
+    #[arg(long)]
+    base_dir: Option<PathBuf>,
+
+    /// Index of the `sudo` pallet in the relay chain runtime.
+    #[arg(long, default_value_t = 255)]
+    sudo_pallet_index: u8,
+
+    /// Index of the `parasSudoWrapper` pallet in the relay chain runtime.
+    #[arg(long, default_value_t = 250)]
+    paras_sudo_wrapper_pallet_index: u8,
+
+    /// Seconds to wait for the relay chain to include the first block of every parachain.
+    ///
+    /// Parachains are onboarded at the session after next, which takes a few minutes on a relay
+    /// chain built with the `fast-runtime` feature, and hours without it.
+    #[arg(long, default_value_t = 600)]
+    timeout: u64,
+
+    /// Tear the network down once every parachain has a block included, instead of running until
+    /// interrupted.
+    #[arg(long)]
+    exit_on_inclusion: bool,
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    match run(Cli::parse()).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Run the network, and tear it down once done, interrupted or failed.
+async fn run(cli: Cli) -> Result<(), String> {
+    let config = NetworkConfig::load(&cli.config)?;
+
+    let base_dir = match &cli.base_dir {
+        Some(dir) => dir.clone(),
+        None => std::env::temp_dir().join(format!("parachain-launcher-{}", std::process::id())),
+    };
+    std::fs::create_dir_all(&base_dir)
+        .map_err(|e| format!("Error creating `{}`: {}", base_dir.display(), e))?;
+    println!("Network directory: {}", base_dir.display());
+
+    let mut nodes = Vec::new();
+    let result = tokio::select! {
+        result = launch(&cli, &config, &base_dir, &mut nodes) => result,
+        _ = tokio::signal::ctrl_c() => Ok(()),
+    };
+
+    println!("Stopping {} nodes", nodes.len());
+    for node in nodes.into_iter().rev() {
+        let name = node.name.clone();
+        if let Err(e) = node.stop().await {
+            eprintln!("Failed to stop {}: {}", name, e);
+        }
+    }
+    match (&result, &cli.base_dir) {
+        (Ok(()), None) => std::fs::remove_dir_all(&base_dir)
+            .map_err(|e| format!("Error removing `{}`: {}", base_dir.display(), e))?,
+        (Err(_), _) => eprintln!("Node logs are kept in {}", base_dir.display()),
+        (Ok(()), Some(_)) => {}
+    }
+    result
+}
+
+/// Launch the network, pushing every spawned node into `nodes`, and wait for the parachains to
+/// have blocks included. Unless `--exit-on-inclusion` is set, the network then runs until
+/// interrupted.
+async fn launch(
+    cli: &Cli,
+    config: &NetworkConfig,
+    base_dir: &Path,
+    nodes: &mut Vec<Node>,
+) -> Result<(), String> {
+    let relay_binary = cli
+        .relay_binary
+        .as_deref()
+        .or(config.relaychain.default_command.as_deref())
+        .ok_or("No relay chain binary, pass `--relay-binary`")?;
+    let mut ports = Ports::default();
+    let mut port = || ports.next().map_err(|e| format!("No free port: {}", e));
+
+    let relay_spec = spec::relay_chain(relay_binary, &config.relaychain.chain, base_dir).await?;
+    let mut relay_bootnode = None;
+    for node_config in &config.relaychain.nodes {
+        let (p2p_port, rpc_port) = (port()?, port()?);
+        let key = NodeKey::derive(&format!("relay/{}", node_config.name));
+        let mut command = NodeCommand::new(
+            &node_config.name,
+            node_config.command.as_deref().unwrap_or(relay_binary),
+            &relay_spec.path,
+            &base_dir.join(&node_config.name),
+            p2p_port,
+            rpc_port,
+        );
+        command.arg("--node-key").arg(key.secret());
+        if node_config.validator {
+            if !DEV_VALIDATORS.contains(&node_config.name.as_str()) {
+                return Err(format!(
+                    "The relay chain validator `{}` must be named after a development account: {}",
+                    node_config.name,
+                    DEV_VALIDATORS.join(", ")
+                ));
+            }
+            command
+                .arg(format!("--{}", node_config.name))
+                .arg("--insecure-validator-i-know-what-i-do");
+        }
+        if let Some(bootnode) = &relay_bootnode {
+            command.arg("--bootnodes").arg(bootnode);
+        }
+        relay_bootnode.get_or_insert_with(|| key.multiaddr(p2p_port));
+        command
+            .args(&config.relaychain.default_args)
+            .args(&node_config.args);
+        nodes.push(spawn(&command, rpc_port, base_dir)?);
+    }
+    let relay_bootnode = relay_bootnode.ok_or("The relay chain has no node")?;
+    let relay = nodes[0].rpc_client().await?;
+
+    let sudo = sr25519::Pair::from_string("//Alice", None).expect("static values are valid; qed");
+    let pallets = RelayPallets {
+        sudo: cli.sudo_pallet_index,
+        paras_sudo_wrapper: cli.paras_sudo_wrapper_pallet_index,
+    };
+    for para in &config.parachains {
+        let binary = para
+            .default_command
+            .as_deref()
+            .ok_or_else(|| format!("Parachain {} has no `default_command`", para.id))?;
+        let collators = para
+            .collators
+            .iter()
+            .map(|collator| Collator::new(&collator.name))
+            .collect::<Vec<_>>();
+        let para_spec =
+            spec::parachain(binary, para.id, &relay_spec.id, &collators, base_dir).await?;
+        let genesis = spec::genesis(binary, &para_spec, base_dir).await?;
+
+        let mut para_bootnode = None;
+        for (collator, node_config) in collators.iter().zip(&para.collators) {
+            let collator_dir = base_dir.join(&collator.name);
+            node::insert_key(&collator_dir, &para_spec.id, *b"aura", &collator.suri)?;
+
+            let (p2p_port, rpc_port) = (port()?, port()?);
+            let key = NodeKey::derive(&format!("parachain-{}/{}", para.id, collator.name));
+            let mut command = NodeCommand::new(
+                &collator.name,
+                node_config.command.as_deref().unwrap_or(binary),
+                &para_spec.path,
+                &collator_dir,
+                p2p_port,
+                rpc_port,
+            );
+            command
+                .arg("--collator")
+                .arg("--node-key")
+                .arg(key.secret());
+            if let Some(bootnode) = &para_bootnode {
+                command.arg("--bootnodes").arg(bootnode);
+            }
+            para_bootnode.get_or_insert_with(|| key.multiaddr(p2p_port));
+            command.args(&para.default_args).args(&node_config.args);
+            embedded_relay_node(&mut command, &relay_spec.path, &relay_bootnode, &mut port)?;
+            nodes.push(spawn(&command, rpc_port, base_dir)?);
+        }
+
+        let hash = registration::register(
+            &relay,
+            &pallets,
+            &sudo,
+            para.id,
+            genesis.head,
+            genesis.validation_code,
+        )
+        .await?;
+        println!("Registered para {} in transaction {:?}", para.id, hash);
+    }
+
+    for para in &config.parachains {
+        let number = tokio::time::timeout(
+            Duration::from_secs(cli.timeout),
+            registration::wait_for_first_block(&relay, para.id),
+        )
+        .await
+        .map_err(|_| {
+            format!(
+                "The relay chain included no block of para {} within {} seconds",
+                para.id, cli.timeout
+            )
+        })??;
+        println!("Para {} has block #{} included", para.id, number);
+    }
+
+    if !cli.exit_on_inclusion {
+        println!("The network is running, press Ctrl-C to stop it");
+        std::future::pending::<()>().await;
+    }
+    Ok(())
+}
+
+/// Configure the relay chain node embedded in a collator, after its own arguments.
+fn embedded_relay_node(
+    command: &mut NodeCommand,
+    relay_spec: &Path,
+    relay_bootnode: &str,
+    port: &mut impl FnMut() -> Result<u16, String>,
+) -> Result<(), String> {
+    command
+        .arg("--")
+        .arg("--chain")
+        .arg(relay_spec.display())
+        .arg("--port")
+        .arg(port()?)
+        .arg("--rpc-port")
+        .arg(port()?)
+        .arg("--bootnodes")
+        .arg(relay_bootnode)
+        .args(["--no-mdns", "--no-prometheus", "--no-telemetry"]);
+    Ok(())
+}
+
+fn spawn(command: &NodeCommand, rpc_port: u16, base_dir: &Path) -> Result<Node, String> {
+    let node = Node::spawn(command, rpc_port, base_dir).map_err(|e| e.to_string())?;
+    println!("Started {} with RPC on {}", node.name, node.rpc_url());
+    Ok(node)
+}
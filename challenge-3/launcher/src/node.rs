@@ -0,0 +1,231 @@
+//! Processes of the relay chain nodes and collators.
+
+use std::{
+    collections::HashSet,
+    fs::File,
+    io,
+    net::TcpListener,
+    path::{Path, PathBuf},
+    process::Stdio,
+    time::Duration,
+};
+
+use jsonrpsee::ws_client::{WsClient, WsClientBuilder};
+use sp_core::{blake2_256, ed25519, hexdisplay::HexDisplay, Pair};
+use tokio::process::{Child, Command};
+
+/// How long a node may take to serve RPC requests once spawned.
+const STARTUP_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Arguments passed to every node, keeping the network to the local machine.
+const LOCAL_ARGS: &[&str] = &[
+    "--no-mdns",
+    "--no-prometheus",
+    "--no-telemetry",
+    "--no-hardware-benchmarks",
+];
+
+/// Free ports of the local machine, handed out at most once.
+#[derive(Default)]
+pub struct Ports {
+    used: HashSet<u16>,
+}
+
+impl Ports {
+    /// A port nothing listens on, as allocated by the OS.
+    pub fn next(&mut self) -> io::Result<u16> {
+        loop {
+            let port = TcpListener::bind(("127.0.0.1", 0))?.local_addr()?.port();
+            if self.used.insert(port) {
+                return Ok(port);
+            }
+        }
+    }
+}
+
+/// Network identity of a node, known before the node starts so that others can boot from it.
+pub struct NodeKey([u8; 32]);
+
+impl NodeKey {
+    /// The node key of the node labelled `label`.
+    pub fn derive(label: &str) -> Self {
+        Self(blake2_256(label.as_bytes()))
+    }
+
+    /// The hex encoded secret, as taken by `--node-key`.
+    pub fn secret(&self) -> String {
+        HexDisplay::from(&self.0).to_string()
+    }
+
+    /// The libp2p peer ID: an identity multihash of the protobuf encoded Ed25519 public key.
+    pub fn peer_id(&self) -> String {
+        let public = ed25519::Pair::from_seed(&self.0).public();
+        let mut bytes = vec![0x00, 0x24, 0x08, 0x01, 0x12, 0x20];
+        bytes.extend_from_slice(public.as_ref());
+        bs58::encode(bytes).into_string()
+    }
+
+    /// The address of the node listening on `port` of the local machine.
+    pub fn multiaddr(&self, port: u16) -> String {
+        format!("/ip4/127.0.0.1/tcp/{}/p2p/{}", port, self.peer_id())
+    }
+}
+
+/// The command line of a node.
+pub struct NodeCommand {
+    /// Name of the node, also naming its directory and log file.
+    pub name: String,
+    /// Binary of the node.
+    pub binary: PathBuf,
+    /// Arguments of the node.
+    pub args: Vec<String>,
+}
+
+impl NodeCommand {
+    /// A node of `binary` named `name`, with its network and RPC server on `p2p_port` and
+    /// `rpc_port`.
+    pub fn new(
+        name: &str,
+        binary: &Path,
+        chain: &Path,
+        base_path: &Path,
+        p2p_port: u16,
+        rpc_port: u16,
+    ) -> Self {
+        let mut command = Self {
+            name: name.to_string(),
+            binary: binary.to_path_buf(),
+            args: Vec::new(),
+        };
+        command
+            .arg("--chain")
+            .arg(chain.display())
+            .arg("--base-path")
+            .arg(base_path.display())
+            .arg("--port")
+            .arg(p2p_port)
+            .arg("--rpc-port")
+            .arg(rpc_port)
+            .args(LOCAL_ARGS);
+        command
+    }
+
+    /// Append `arg` to the arguments.
+    pub fn arg(&mut self, arg: impl ToString) -> &mut Self {
+        self.args.push(arg.to_string());
+        self
+    }
+
+    /// Append `args` to the arguments.
+    pub fn args(&mut self, args: impl IntoIterator<Item = impl ToString>) -> &mut Self {
+        self.args
+            .extend(args.into_iter().map(|arg| arg.to_string()));
+        self
+    }
+}
+
+/// A running node.
+pub struct Node {
+    /// Name of the node.
+    pub name: String,
+    /// RPC port of the node.
+    pub rpc_port: u16,
+    process: Child,
+}
+
+impl Node {
+    /// Spawn `command`, logging into `<name>.log` in `log_dir`.
+    pub fn spawn(command: &NodeCommand, rpc_port: u16, log_dir: &Path) -> io::Result<Self> {
+        let log = File::create(log_dir.join(format!("{}.log", command.name)))?;
+        let process = Command::new(&command.binary)
+            .args(&command.args)
+            .stdin(Stdio::null())
+            .stdout(log.try_clone()?)
+            .stderr(log)
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|e| {
+                io::Error::new(
+                    e.kind(),
+                    format!("cannot run `{}`: {}", command.binary.display(), e),
+                )
+            })?;
+        Ok(Self {
+            name: command.name.clone(),
+            rpc_port,
+            process,
+        })
+    }
+
+    /// The WebSocket URL of the RPC server of the node.
+    pub fn rpc_url(&self) -> String {
+        format!("ws://127.0.0.1:{}", self.rpc_port)
+    }
+
+    /// Connect to the RPC server of the node, waiting for the node to start it.
+    pub async fn rpc_client(&mut self) -> Result<WsClient, String> {
+        let deadline = tokio::time::Instant::now() + STARTUP_TIMEOUT;
+        loop {
+            if let Some(status) = self.process.try_wait().map_err(|e| e.to_string())? {
+                return Err(format!("{} exited with {}", self.name, status));
+            }
+            match WsClientBuilder::default().build(self.rpc_url()).await {
+                Ok(client) => return Ok(client),
+                Err(e) if tokio::time::Instant::now() > deadline => {
+                    return Err(format!("{} does not serve RPC requests: {}", self.name, e))
+                }
+                Err(_) => tokio::time::sleep(Duration::from_millis(500)).await,
+            }
+        }
+    }
+
+    /// Stop the node and wait for its process to exit.
+    pub async fn stop(mut self) -> io::Result<()> {
+        if self.process.try_wait()?.is_none() {
+            self.process.kill().await?;
+        }
+        Ok(())
+    }
+}
+
+/// Store the sr25519 key derived from `suri` as the key of type `key_type` in the keystore of the
+/// chain `chain_id`, under `base_path`.
+///
+/// This is the layout of the local keystore of Substrate nodes: a file named after the hex encoded
+/// key type and public key, holding the secret URI as a JSON string.
+pub fn insert_key(
+    base_path: &Path,
+    chain_id: &str,
+    key_type: [u8; 4],
+    suri: &str,
+) -> Result<(), String> {
+    let public = sp_core::sr25519::Pair::from_string(suri, None)
+        .map_err(|e| format!("invalid secret URI `{}`: {:?}", suri, e))?
+        .public();
+    let keystore = base_path.join("chains").join(chain_id).join("keystore");
+    std::fs::create_dir_all(&keystore).map_err(|e| e.to_string())?;
+    let file = format!(
+        "{}{}",
+        HexDisplay::from(&key_type),
+        HexDisplay::from(&public.as_ref())
+    );
+    let suri = serde_json::to_string(suri).map_err(|e| e.to_string())?;
+    std::fs::write(keystore.join(file), suri).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn peer_ids_match_libp2p() {
+        assert_eq!(
+            NodeKey([0; 32]).peer_id(),
+            "12D3KooWDpJ7As7BWAwRMfu1VU2WCqNjvq387JEYKDBj4kx6nXTN"
+        );
+        assert_eq!(
+            NodeKey([1; 32]).peer_id(),
+            "12D3KooWK99VoVxNE7XzyBwXEzW7xhK7Gpv85r9F3V3fyKSUKPH5"
+        );
+    }
+}
@@ -0,0 +1,165 @@
+//! Registration of a parachain on the relay chain through sudo, and the wait for its first block.
+//!
+//! The launcher talks to any relay chain binary, so it does not link the relay chain runtime: the
+//! calls and the signed transaction are encoded by hand for the Rococo runtime of the Polkadot SDK
+//! release the nodes are built against.
+
+use std::time::Duration;
+
+use codec::{Compact, Decode, Encode};
+use jsonrpsee::{core::client::ClientT, rpc_params, ws_client::WsClient};
+use serde::Deserialize;
+use sp_core::{blake2_256, sr25519, twox_128, twox_64, Bytes, Pair, H256};
+
+/// Call index of `sudo.sudo`.
+const SUDO_CALL_INDEX: u8 = 0;
+/// Call index of `parasSudoWrapper.sudo_schedule_para_initialize`.
+const SCHEDULE_PARA_INITIALIZE_CALL_INDEX: u8 = 0;
+
+/// Version 4 of the extrinsic format, with the signed bit set.
+const SIGNED_EXTRINSIC_V4: u8 = 0b1000_0100;
+
+/// How often the relay chain is polled for the head of the parachain.
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Indices of the relay chain pallets the registration goes through.
+pub struct RelayPallets {
+    /// Index of `sudo`.
+    pub sudo: u8,
+    /// Index of `parasSudoWrapper`.
+    pub paras_sudo_wrapper: u8,
+}
+
+/// The fields of the runtime version that transactions are signed against.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RuntimeVersion {
+    spec_version: u32,
+    transaction_version: u32,
+}
+
+/// Register parachain `para_id` with sudo `signer`, scheduling its onboarding at the session after
+/// next. Returns the hash of the transaction.
+pub async fn register(
+    relay: &WsClient,
+    pallets: &RelayPallets,
+    signer: &sr25519::Pair,
+    para_id: u32,
+    genesis_head: Vec<u8>,
+    validation_code: Vec<u8>,
+) -> Result<H256, String> {
+    let call = (
+        pallets.sudo,
+        SUDO_CALL_INDEX,
+        pallets.paras_sudo_wrapper,
+        SCHEDULE_PARA_INITIALIZE_CALL_INDEX,
+        // `ParaGenesisArgs`, of a parachain rather than a parathread.
+        para_id,
+        genesis_head,
+        validation_code,
+        true,
+    )
+        .encode();
+    let extrinsic = signed_extrinsic(relay, signer, call).await?;
+    relay
+        .request("author_submitExtrinsic", rpc_params![Bytes(extrinsic)])
+        .await
+        .map_err(|e| format!("the registration of para {} failed: {}", para_id, e))
+}
+
+/// Sign `call` by `signer` as an immortal transaction with no tip.
+///
+/// The signed extensions are those of Rococo: `CheckNonZeroSender`, `CheckSpecVersion`,
+/// `CheckTxVersion`, `CheckGenesis`, `CheckMortality`, `CheckNonce`, `CheckWeight`,
+/// `ChargeTransactionPayment` and `CheckMetadataHash`.
+async fn signed_extrinsic(
+    relay: &WsClient,
+    signer: &sr25519::Pair,
+    call: Vec<u8>,
+) -> Result<Vec<u8>, String> {
+    let account = signer.public();
+    let nonce: u32 = request(relay, "system_accountNextIndex", rpc_params![account]).await?;
+    let genesis_hash: H256 = request(relay, "chain_getBlockHash", rpc_params![0]).await?;
+    let version: RuntimeVersion = request(relay, "state_getRuntimeVersion", rpc_params![]).await?;
+
+    // Immortal era, nonce, tip and disabled metadata hash check.
+    let extra = (0u8, Compact(nonce), Compact(0u128), 0u8).encode();
+    let additional = (
+        version.spec_version,
+        version.transaction_version,
+        genesis_hash,
+        genesis_hash,
+        None::<H256>,
+    )
+        .encode();
+
+    let payload = [&call[..], &extra, &additional].concat();
+    let signature = if payload.len() > 256 {
+        signer.sign(&blake2_256(&payload))
+    } else {
+        signer.sign(&payload)
+    };
+
+    let mut extrinsic = vec![SIGNED_EXTRINSIC_V4];
+    // `MultiAddress::Id` and `MultiSignature::Sr25519`.
+    (0u8, account, 1u8, signature).encode_to(&mut extrinsic);
+    extrinsic.extend(extra);
+    extrinsic.extend(call);
+    Ok(extrinsic.encode())
+}
+
+/// Wait for the relay chain to include a block of parachain `para_id`, returning its number.
+pub async fn wait_for_first_block(relay: &WsClient, para_id: u32) -> Result<u32, String> {
+    let key = Bytes(
+        [
+            &twox_128(b"Paras")[..],
+            &twox_128(b"Heads"),
+            &twox_64(&para_id.encode()),
+            &para_id.encode(),
+        ]
+        .concat(),
+    );
+    loop {
+        let head: Option<Bytes> =
+            request(relay, "state_getStorage", rpc_params![key.clone()]).await?;
+        if let Some(number) = head.map(|head| head_number(&head)).transpose()? {
+            if number > 0 {
+                return Ok(number);
+            }
+        }
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
+
+/// Number of the block whose encoded `HeadData` is `head`, which is a header starting with the
+/// parent hash.
+fn head_number(mut head: &[u8]) -> Result<u32, String> {
+    let header = Vec::<u8>::decode(&mut head).map_err(|e| e.to_string())?;
+    let mut number = header.get(32..).ok_or("the parachain head is too short")?;
+    Compact::<u32>::decode(&mut number)
+        .map(|number| number.0)
+        .map_err(|e| e.to_string())
+}
+
+async fn request<R: serde::de::DeserializeOwned>(
+    relay: &WsClient,
+    method: &str,
+    params: jsonrpsee::core::params::ArrayParams,
+) -> Result<R, String> {
+    relay
+        .request(method, params)
+        .await
+        .map_err(|e| format!("`{}` failed: {}", method, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn head_number_is_decoded_after_the_parent_hash() {
+        let header = (H256::repeat_byte(1), Compact(42u32), H256::zero()).encode();
+
+        assert_eq!(head_number(&header.encode()), Ok(42));
+    }
+}
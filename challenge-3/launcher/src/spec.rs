@@ -0,0 +1,184 @@
+//! Chain specs of the network, generated by the node binaries themselves.
+
+use std::path::{Path, PathBuf};
+
+use serde_json::json;
+use sp_core::{crypto::Ss58Codec, sr25519, Pair};
+use tokio::process::Command;
+
+/// Balance of the accounts endowed on a parachain.
+const ENDOWMENT: u128 = 1 << 60;
+
+/// A chain spec written to disk.
+pub struct ChainSpec {
+    /// Path of the raw chain spec.
+    pub path: PathBuf,
+    /// ID of the chain.
+    pub id: String,
+}
+
+/// A collator of a parachain, with its account and Aura key derived from the same secret URI.
+pub struct Collator {
+    /// Name of the collator.
+    pub name: String,
+    /// Secret URI of the account and Aura key.
+    pub suri: String,
+}
+
+impl Collator {
+    /// A collator using the keys derived from `//<name>`.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            suri: format!("//{}", name),
+        }
+    }
+
+    fn public(&self) -> String {
+        sr25519::Pair::from_string(&self.suri, None)
+            .expect("derived from a name, the secret URI is valid; qed")
+            .public()
+            .to_ss58check()
+    }
+}
+
+/// The genesis head and validation code of a parachain.
+pub struct Genesis {
+    /// The encoded genesis header.
+    pub head: Vec<u8>,
+    /// The runtime of the parachain.
+    pub validation_code: Vec<u8>,
+}
+
+/// Build the raw chain spec of relay chain `chain` with `binary` into `dir`.
+pub async fn relay_chain(binary: &Path, chain: &str, dir: &Path) -> Result<ChainSpec, String> {
+    let spec = run(
+        binary,
+        &[
+            "build-spec",
+            "--chain",
+            chain,
+            "--disable-default-bootnode",
+            "--raw",
+        ],
+    )
+    .await?;
+    write(dir.join(format!("{}.json", chain)), spec).await
+}
+
+/// Build the raw chain spec of parachain `para_id` of `relay_chain` with `binary` into `dir`.
+///
+/// The spec is generated from a `live:` config of the collator, with `collators` as invulnerables
+/// and Alice as sudo key.
+pub async fn parachain(
+    binary: &Path,
+    para_id: u32,
+    relay_chain: &str,
+    collators: &[Collator],
+    dir: &Path,
+) -> Result<ChainSpec, String> {
+    let alice = sr25519::Pair::from_string("//Alice", None)
+        .expect("static values are valid; qed")
+        .public()
+        .to_ss58check();
+    let endowments = collators
+        .iter()
+        .map(Collator::public)
+        .chain([alice.clone()])
+        .map(|account| json!({ "account": account, "balance": ENDOWMENT }))
+        .collect::<Vec<_>>();
+    let config = json!({
+        "name": format!("Parachain {}", para_id),
+        "id": format!("parachain_{}", para_id),
+        "chainType": "Local",
+        "paraId": para_id,
+        "relayChain": relay_chain,
+        "tokenSymbol": "UNIT",
+        "tokenDecimals": 12,
+        "collators": collators
+            .iter()
+            .map(|collator| json!({ "account": collator.public(), "aura": collator.public() }))
+            .collect::<Vec<_>>(),
+        "endowments": endowments,
+        "sudo": alice,
+    });
+    let config_path = dir.join(format!("parachain-{}-config.json", para_id));
+    tokio::fs::write(&config_path, config.to_string())
+        .await
+        .map_err(|e| format!("Error writing `{}`: {}", config_path.display(), e))?;
+
+    let chain = format!("live:{}", config_path.display());
+    let spec = run(
+        binary,
+        &[
+            "build-spec",
+            "--chain",
+            &chain,
+            "--disable-default-bootnode",
+            "--raw",
+        ],
+    )
+    .await?;
+    write(dir.join(format!("parachain-{}.json", para_id)), spec).await
+}
+
+/// Export the genesis head and validation code of the parachain of `spec` with `binary`, through
+/// a registration bundle written into `dir`.
+pub async fn genesis(binary: &Path, spec: &ChainSpec, dir: &Path) -> Result<Genesis, String> {
+    let bundle = dir.join(format!("{}-registration", spec.id));
+    let base_path = dir.join(format!("{}-genesis", spec.id));
+    run(
+        binary,
+        &[
+            "export-registration",
+            &bundle.display().to_string(),
+            "--chain",
+            &spec.path.display().to_string(),
+            "--base-path",
+            &base_path.display().to_string(),
+        ],
+    )
+    .await?;
+    Ok(Genesis {
+        head: read_hex(&bundle.join("genesis-head")).await?,
+        validation_code: read_hex(&bundle.join("genesis-wasm")).await?,
+    })
+}
+
+/// Run `binary` with `args`, returning its standard output.
+async fn run(binary: &Path, args: &[&str]) -> Result<Vec<u8>, String> {
+    let output = Command::new(binary)
+        .args(args)
+        .output()
+        .await
+        .map_err(|e| format!("cannot run `{}`: {}", binary.display(), e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "`{} {}` failed: {}",
+            binary.display(),
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+    Ok(output.stdout)
+}
+
+/// Write the raw chain spec `spec` to `path`.
+async fn write(path: PathBuf, spec: Vec<u8>) -> Result<ChainSpec, String> {
+    let id = serde_json::from_slice::<serde_json::Value>(&spec)
+        .ok()
+        .and_then(|spec| spec["id"].as_str().map(str::to_string))
+        .ok_or_else(|| format!("the chain spec for `{}` has no ID", path.display()))?;
+    tokio::fs::write(&path, spec)
+        .await
+        .map_err(|e| format!("Error writing `{}`: {}", path.display(), e))?;
+    Ok(ChainSpec { path, id })
+}
+
+async fn read_hex(path: &Path) -> Result<Vec<u8>, String> {
+    let hex = tokio::fs::read_to_string(path)
+        .await
+        .map_err(|e| format!("Error reading `{}`: {}", path.display(), e))?;
+    sp_core::bytes::from_hex(hex.trim())
+        .map_err(|e| format!("Invalid hex in `{}`: {:?}", path.display(), e))
+}
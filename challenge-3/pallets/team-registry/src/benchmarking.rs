@@ -0,0 +1,154 @@
+// This is free and unencumbered software released into the public domain.
+//
+// Anyone is free to copy, modify, publish, use, compile, sell, or
+// distribute this software, either in source code form or as a compiled
+// binary, for any purpose, commercial or non-commercial, and by any
+// means.
+//
+// In jurisdictions that recognize copyright laws, the author or authors
+// of this software dedicate any and all copyright interest in the
+// software to the public domain. We make this dedication for the benefit
+// of the public at large and to the detriment of our heirs and
+// successors. We intend this dedication to be an overt act of
+// relinquishment in perpetuity of all present and future rights to this
+// software under copyright law.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.
+// IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY CLAIM, DAMAGES OR
+// OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE,
+// ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+//
+// For more information, please refer to <http://unlicense.org>
+
+//! Benchmarking setup for pallet-team-registry
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as TeamRegistry;
+use alloc::vec;
+use frame_benchmarking::v2::*;
+use frame_support::traits::{
+    fungible::{Inspect, Mutate},
+    Get,
+};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Saturating;
+
+/// An account holding enough funds to register the largest possible profile.
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+    let account: T::AccountId = account(name, index, 0);
+    let deposit = TeamRegistry::<T>::deposit_for(
+        T::MaxDisplayNameLength::get() as usize,
+        T::MaxDiscordLength::get() as usize,
+    );
+    let balance =
+        T::Currency::minimum_balance().saturating_add(deposit.saturating_mul(2u32.into()));
+    T::Currency::set_balance(&account, balance);
+    account
+}
+
+/// Register the largest possible profile for `who`.
+fn register_profile<T: Config>(who: &T::AccountId) -> Result<(), BenchmarkError> {
+    TeamRegistry::<T>::set_profile(
+        RawOrigin::Signed(who.clone()).into(),
+        vec![b'a'; T::MaxDisplayNameLength::get() as usize],
+        [1u8; 32],
+        vec![b'd'; T::MaxDiscordLength::get() as usize],
+    )?;
+    Ok(())
+}
+
+/// Appoint `count` registrars and return the last one.
+fn add_registrars<T: Config>(count: u32) -> Result<T::AccountId, BenchmarkError> {
+    let origin =
+        T::RegistrarOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+    let mut registrar = account("registrar", 0, 0);
+    for i in 0..count {
+        registrar = account("registrar", i, 0);
+        TeamRegistry::<T>::add_registrar(origin.clone(), registrar.clone())?;
+    }
+    Ok(registrar)
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn set_profile() {
+        let caller = funded_account::<T>("caller", 0);
+        let display_name = vec![b'a'; T::MaxDisplayNameLength::get() as usize];
+        let discord = vec![b'd'; T::MaxDiscordLength::get() as usize];
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller.clone()),
+            display_name,
+            [1u8; 32],
+            discord,
+        );
+
+        assert!(Profiles::<T>::contains_key(&caller));
+    }
+
+    #[benchmark]
+    fn clear_profile() -> Result<(), BenchmarkError> {
+        let caller = funded_account::<T>("caller", 0);
+        register_profile::<T>(&caller)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()));
+
+        assert!(!Profiles::<T>::contains_key(&caller));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn verify_profile(r: Linear<1, { T::MaxRegistrars::get() }>) -> Result<(), BenchmarkError> {
+        let who = funded_account::<T>("member", 0);
+        register_profile::<T>(&who)?;
+        // The last registrar is the worst case for the registrar lookup.
+        let registrar = add_registrars::<T>(r)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(registrar), who.clone());
+
+        assert!(Profiles::<T>::get(&who).map_or(false, |profile| profile.verified));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn add_registrar(
+        r: Linear<1, { T::MaxRegistrars::get().saturating_sub(1) }>,
+    ) -> Result<(), BenchmarkError> {
+        add_registrars::<T>(r)?;
+        let origin =
+            T::RegistrarOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let registrar: T::AccountId = account("new-registrar", 0, 0);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, registrar.clone());
+
+        assert!(Registrars::<T>::get().contains(&registrar));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn remove_registrar(r: Linear<1, { T::MaxRegistrars::get() }>) -> Result<(), BenchmarkError> {
+        let registrar = add_registrars::<T>(r)?;
+        let origin =
+            T::RegistrarOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, registrar.clone());
+
+        assert!(!Registrars::<T>::get().contains(&registrar));
+        Ok(())
+    }
+
+    impl_benchmark_test_suite!(TeamRegistry, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,380 @@
+// This is free and unencumbered software released into the public domain.
+//
+// Anyone is free to copy, modify, publish, use, compile, sell, or
+// distribute this software, either in source code form or as a compiled
+// binary, for any purpose, commercial or non-commercial, and by any
+// means.
+//
+// In jurisdictions that recognize copyright laws, the author or authors
+// of this software dedicate any and all copyright interest in the
+// software to the public domain. We make this dedication for the benefit
+// of the public at large and to the detriment of our heirs and
+// successors. We intend this dedication to be an overt act of
+// relinquishment in perpetuity of all present and future rights to this
+// software under copyright law.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.
+// IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY CLAIM, DAMAGES OR
+// OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE,
+// ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+//
+// For more information, please refer to <http://unlicense.org>
+
+//! # Team Registry Pallet
+//!
+//! An on-chain registry of team member profiles.
+//!
+//! Any account can register a profile made of a display name, the hash of an email address and a
+//! Discord handle. Registering a profile places a deposit on hold, proportional to the size of
+//! the profile, which is released when the profile is cleared.
+//!
+//! Profiles can be marked as verified by `Root` or by one of the registrars appointed by the
+//! [`Config::RegistrarOrigin`]. Any change to a profile resets its verification.
+//!
+//! ## Dispatchable Functions
+//!
+//! - [`set_profile`](Pallet::set_profile): Register or update the profile of the caller.
+//! - [`clear_profile`](Pallet::clear_profile): Remove the profile of the caller and release the
+//!   deposit.
+//! - [`verify_profile`](Pallet::verify_profile): Mark a profile as verified.
+//! - [`add_registrar`](Pallet::add_registrar): Appoint a registrar.
+//! - [`remove_registrar`](Pallet::remove_registrar): Dismiss a registrar.
+//!
+//! ## Migrations
+//!
+//! Storage layout changes bump the pallet [`STORAGE_VERSION`] and come with a
+//! [`VersionedMigration`](frame_support::migrations::VersionedMigration) in [`migrations`], to be
+//! added to the `Migrations` of the runtime.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod migrations;
+pub mod weights;
+pub use weights::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use alloc::vec::Vec;
+    use core::cmp::Ordering;
+    use frame_support::{
+        pallet_prelude::*,
+        traits::{
+            fungible::{Inspect, Mutate, MutateHold},
+            tokens::Precision,
+        },
+    };
+    use frame_system::pallet_prelude::*;
+    use sp_runtime::traits::{Saturating, Zero};
+
+    pub(crate) type BalanceOf<T> =
+        <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+    /// The profile of a team member.
+    #[derive(
+        CloneNoBound,
+        Encode,
+        Decode,
+        EqNoBound,
+        PartialEqNoBound,
+        RuntimeDebugNoBound,
+        TypeInfo,
+        MaxEncodedLen,
+    )]
+    #[scale_info(skip_type_params(T))]
+    #[codec(mel_bound())]
+    pub struct Profile<T: Config> {
+        /// The name displayed for the member.
+        pub display_name: BoundedVec<u8, T::MaxDisplayNameLength>,
+        /// The hash of the email address of the member.
+        pub email_hash: [u8; 32],
+        /// The Discord handle of the member.
+        pub discord: BoundedVec<u8, T::MaxDiscordLength>,
+        /// The amount held from the member for storing the profile.
+        pub deposit: BalanceOf<T>,
+        /// Whether the profile was verified by `Root` or a registrar.
+        pub verified: bool,
+    }
+
+    /// The in-code storage version.
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+    #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The currency used for the profile deposits.
+        type Currency: Mutate<Self::AccountId>
+            + MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+
+        /// The overarching hold reason.
+        type RuntimeHoldReason: From<HoldReason>;
+
+        /// The amount held for registering a profile.
+        #[pallet::constant]
+        type BasicDeposit: Get<BalanceOf<Self>>;
+
+        /// The amount held per byte of the display name and Discord handle.
+        #[pallet::constant]
+        type ByteDeposit: Get<BalanceOf<Self>>;
+
+        /// The maximum length of a display name.
+        #[pallet::constant]
+        type MaxDisplayNameLength: Get<u32>;
+
+        /// The maximum length of a Discord handle.
+        #[pallet::constant]
+        type MaxDiscordLength: Get<u32>;
+
+        /// The maximum number of registrars.
+        #[pallet::constant]
+        type MaxRegistrars: Get<u32>;
+
+        /// The origin allowed to appoint and dismiss registrars.
+        type RegistrarOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    /// A reason for the pallet placing a hold on funds.
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// Funds are held for storing a profile.
+        #[codec(index = 0)]
+        ProfileDeposit,
+    }
+
+    /// The profiles of the team members.
+    #[pallet::storage]
+    pub type Profiles<T: Config> = CountedStorageMap<_, Blake2_128Concat, T::AccountId, Profile<T>>;
+
+    /// The accounts allowed to verify profiles.
+    #[pallet::storage]
+    pub type Registrars<T: Config> =
+        StorageValue<_, BoundedVec<T::AccountId, T::MaxRegistrars>, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A profile was registered or updated.
+        ProfileSet {
+            who: T::AccountId,
+            deposit: BalanceOf<T>,
+        },
+        /// A profile was cleared and its deposit released.
+        ProfileCleared {
+            who: T::AccountId,
+            deposit: BalanceOf<T>,
+        },
+        /// A profile was verified. The registrar is `None` when verified by `Root`.
+        ProfileVerified {
+            who: T::AccountId,
+            registrar: Option<T::AccountId>,
+        },
+        /// A registrar was appointed.
+        RegistrarAdded { registrar: T::AccountId },
+        /// A registrar was dismissed.
+        RegistrarRemoved { registrar: T::AccountId },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The display name is empty.
+        EmptyDisplayName,
+        /// The display name is longer than `MaxDisplayNameLength`.
+        DisplayNameTooLong,
+        /// The Discord handle is longer than `MaxDiscordLength`.
+        DiscordTooLong,
+        /// The account has no profile.
+        NoProfile,
+        /// The profile is already verified.
+        AlreadyVerified,
+        /// The origin is neither `Root` nor a registrar.
+        NotRegistrar,
+        /// The account is already a registrar.
+        AlreadyRegistrar,
+        /// There are already `MaxRegistrars` registrars.
+        TooManyRegistrars,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Register or update the profile of the caller.
+        ///
+        /// The deposit is adjusted to the size of the new profile, and the profile has to be
+        /// verified again.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::set_profile())]
+        pub fn set_profile(
+            origin: OriginFor<T>,
+            display_name: Vec<u8>,
+            email_hash: [u8; 32],
+            discord: Vec<u8>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(!display_name.is_empty(), Error::<T>::EmptyDisplayName);
+            let display_name: BoundedVec<_, T::MaxDisplayNameLength> = display_name
+                .try_into()
+                .map_err(|_| Error::<T>::DisplayNameTooLong)?;
+            let discord: BoundedVec<_, T::MaxDiscordLength> =
+                discord.try_into().map_err(|_| Error::<T>::DiscordTooLong)?;
+
+            let deposit = Self::deposit_for(display_name.len(), discord.len());
+            let old_deposit = Profiles::<T>::get(&who)
+                .map(|profile| profile.deposit)
+                .unwrap_or_else(Zero::zero);
+            let reason: T::RuntimeHoldReason = HoldReason::ProfileDeposit.into();
+            match deposit.cmp(&old_deposit) {
+                Ordering::Greater => {
+                    T::Currency::hold(&reason, &who, deposit.saturating_sub(old_deposit))?;
+                }
+                Ordering::Less => {
+                    T::Currency::release(
+                        &reason,
+                        &who,
+                        old_deposit.saturating_sub(deposit),
+                        Precision::Exact,
+                    )?;
+                }
+                Ordering::Equal => {}
+            }
+
+            Profiles::<T>::insert(
+                &who,
+                Profile {
+                    display_name,
+                    email_hash,
+                    discord,
+                    deposit,
+                    verified: false,
+                },
+            );
+            Self::deposit_event(Event::ProfileSet { who, deposit });
+            Ok(())
+        }
+
+        /// Remove the profile of the caller and release its deposit.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::clear_profile())]
+        pub fn clear_profile(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let profile = Profiles::<T>::take(&who).ok_or(Error::<T>::NoProfile)?;
+            T::Currency::release(
+                &HoldReason::ProfileDeposit.into(),
+                &who,
+                profile.deposit,
+                Precision::BestEffort,
+            )?;
+
+            Self::deposit_event(Event::ProfileCleared {
+                who,
+                deposit: profile.deposit,
+            });
+            Ok(())
+        }
+
+        /// Mark the profile of `who` as verified.
+        ///
+        /// The origin must be `Root` or a registrar.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::verify_profile(T::MaxRegistrars::get()))]
+        pub fn verify_profile(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+            let registrar = match ensure_signed_or_root(origin)? {
+                Some(signer) => {
+                    ensure!(
+                        Registrars::<T>::get().contains(&signer),
+                        Error::<T>::NotRegistrar
+                    );
+                    Some(signer)
+                }
+                None => None,
+            };
+
+            Profiles::<T>::try_mutate(&who, |profile| -> DispatchResult {
+                let profile = profile.as_mut().ok_or(Error::<T>::NoProfile)?;
+                ensure!(!profile.verified, Error::<T>::AlreadyVerified);
+                profile.verified = true;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::ProfileVerified { who, registrar });
+            Ok(())
+        }
+
+        /// Appoint `registrar` as a registrar.
+        ///
+        /// The origin must be the `RegistrarOrigin`.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::add_registrar(T::MaxRegistrars::get()))]
+        pub fn add_registrar(origin: OriginFor<T>, registrar: T::AccountId) -> DispatchResult {
+            T::RegistrarOrigin::ensure_origin(origin)?;
+
+            Registrars::<T>::try_mutate(|registrars| -> DispatchResult {
+                ensure!(
+                    !registrars.contains(&registrar),
+                    Error::<T>::AlreadyRegistrar
+                );
+                registrars
+                    .try_push(registrar.clone())
+                    .map_err(|_| Error::<T>::TooManyRegistrars)?;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::RegistrarAdded { registrar });
+            Ok(())
+        }
+
+        /// Dismiss `registrar`.
+        ///
+        /// The origin must be the `RegistrarOrigin`.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::remove_registrar(T::MaxRegistrars::get()))]
+        pub fn remove_registrar(origin: OriginFor<T>, registrar: T::AccountId) -> DispatchResult {
+            T::RegistrarOrigin::ensure_origin(origin)?;
+
+            Registrars::<T>::try_mutate(|registrars| -> DispatchResult {
+                let index = registrars
+                    .iter()
+                    .position(|r| r == &registrar)
+                    .ok_or(Error::<T>::NotRegistrar)?;
+                registrars.remove(index);
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::RegistrarRemoved { registrar });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// The deposit held for a profile with the given field lengths.
+        pub fn deposit_for(display_name_len: usize, discord_len: usize) -> BalanceOf<T> {
+            let bytes = (display_name_len.saturating_add(discord_len)) as u32;
+            T::BasicDeposit::get()
+                .saturating_add(T::ByteDeposit::get().saturating_mul(bytes.into()))
+        }
+    }
+}
@@ -0,0 +1,97 @@
+// This is free and unencumbered software released into the public domain.
+//
+// Anyone is free to copy, modify, publish, use, compile, sell, or
+// distribute this software, either in source code form or as a compiled
+// binary, for any purpose, commercial or non-commercial, and by any
+// means.
+//
+// In jurisdictions that recognize copyright laws, the author or authors
+// of this software dedicate any and all copyright interest in the
+// software to the public domain. We make this dedication for the benefit
+// of the public at large and to the detriment of our heirs and
+// successors. We intend this dedication to be an overt act of
+// relinquishment in perpetuity of all present and future rights to this
+// software under copyright law.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.
+// IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY CLAIM, DAMAGES OR
+// OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE,
+// ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+//
+// For more information, please refer to <http://unlicense.org>
+
+//! Storage migrations of the team registry pallet.
+
+use super::*;
+#[cfg(feature = "try-runtime")]
+use alloc::vec::Vec;
+use frame_support::{
+    migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+};
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+const LOG_TARGET: &str = "runtime::team-registry";
+
+/// Migration to version 1, where [`Profiles`] became a `CountedStorageMap`.
+pub mod v1 {
+    use super::*;
+
+    /// The storage of version 0, as read by the migration checks and tests.
+    #[cfg(any(test, feature = "try-runtime"))]
+    pub(crate) mod v0 {
+        use super::*;
+
+        /// The profiles of the team members, without a counter.
+        #[frame_support::storage_alias]
+        pub type Profiles<T: Config> = StorageMap<
+            Pallet<T>,
+            Blake2_128Concat,
+            <T as frame_system::Config>::AccountId,
+            Profile<T>,
+        >;
+    }
+
+    /// Initializes the counter of [`Profiles`] with the number of existing profiles.
+    ///
+    /// Not version checked, use [`MigrateV0ToV1`] instead.
+    pub struct VersionUncheckedMigrateV0ToV1<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for VersionUncheckedMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let count = Profiles::<T>::initialize_counter();
+            log::info!(target: LOG_TARGET, "Counted {} profiles", count);
+            T::DbWeight::get().reads_writes(count as u64, 1)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok((v0::Profiles::<T>::iter_keys().count() as u32).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let count =
+                u32::decode(&mut &state[..]).map_err(|_| "v1: invalid pre-upgrade state")?;
+            ensure!(
+                Profiles::<T>::count() == count,
+                "v1: the profile counter does not match the number of profiles"
+            );
+            Ok(())
+        }
+    }
+
+    /// Migrates the pallet storage from version 0 to version 1.
+    ///
+    /// Only runs when the on-chain storage version is 0, and bumps it to 1.
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        VersionUncheckedMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
@@ -0,0 +1,269 @@
+// This is free and unencumbered software released into the public domain.
+//
+// Anyone is free to copy, modify, publish, use, compile, sell, or
+// distribute this software, either in source code form or as a compiled
+// binary, for any purpose, commercial or non-commercial, and by any
+// means.
+//
+// In jurisdictions that recognize copyright laws, the author or authors
+// of this software dedicate any and all copyright interest in the
+// software to the public domain. We make this dedication for the benefit
+// of the public at large and to the detriment of our heirs and
+// successors. We intend this dedication to be an overt act of
+// relinquishment in perpetuity of all present and future rights to this
+// software under copyright law.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.
+// IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY CLAIM, DAMAGES OR
+// OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE,
+// ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+//
+// For more information, please refer to <http://unlicense.org>
+
+use crate::{
+    migrations::v1::{v0, MigrateV0ToV1},
+    mock::*,
+    Error, Event, HoldReason, Profile, Profiles, Registrars,
+};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{fungible::InspectHold, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+use sp_runtime::{DispatchError, DispatchResult};
+
+fn held(who: u64) -> u64 {
+    Balances::balance_on_hold(&HoldReason::ProfileDeposit.into(), &who)
+}
+
+fn set_profile(who: u64, display_name: &[u8], discord: &[u8]) -> DispatchResult {
+    TeamRegistry::set_profile(
+        RuntimeOrigin::signed(who),
+        display_name.to_vec(),
+        [7u8; 32],
+        discord.to_vec(),
+    )
+}
+
+#[test]
+fn set_profile_holds_deposit() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(set_profile(ALICE, b"alice", b"alice#1"));
+
+        let deposit = BASIC_DEPOSIT + 12 * BYTE_DEPOSIT;
+        let profile = Profiles::<Test>::get(ALICE).unwrap();
+        assert_eq!(profile.display_name.to_vec(), b"alice".to_vec());
+        assert_eq!(profile.email_hash, [7u8; 32]);
+        assert_eq!(profile.discord.to_vec(), b"alice#1".to_vec());
+        assert_eq!(profile.deposit, deposit);
+        assert!(!profile.verified);
+        assert_eq!(held(ALICE), deposit);
+        System::assert_last_event(
+            Event::ProfileSet {
+                who: ALICE,
+                deposit,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn updating_profile_adjusts_deposit_and_resets_verification() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(set_profile(ALICE, b"alice", b"alice#1"));
+        assert_ok!(TeamRegistry::verify_profile(RuntimeOrigin::root(), ALICE));
+
+        assert_ok!(set_profile(ALICE, b"alice wonderland", b""));
+        assert_eq!(held(ALICE), BASIC_DEPOSIT + 16 * BYTE_DEPOSIT);
+        assert!(!Profiles::<Test>::get(ALICE).unwrap().verified);
+
+        assert_ok!(set_profile(ALICE, b"al", b""));
+        assert_eq!(held(ALICE), BASIC_DEPOSIT + 2 * BYTE_DEPOSIT);
+    });
+}
+
+#[test]
+fn set_profile_validates_fields() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            set_profile(ALICE, b"", b"alice#1"),
+            Error::<Test>::EmptyDisplayName
+        );
+        assert_noop!(
+            set_profile(ALICE, &[b'a'; 17], b"alice#1"),
+            Error::<Test>::DisplayNameTooLong
+        );
+        assert_noop!(
+            set_profile(ALICE, b"alice", &[b'd'; 17]),
+            Error::<Test>::DiscordTooLong
+        );
+    });
+}
+
+#[test]
+fn set_profile_fails_without_funds_for_deposit() {
+    new_test_ext().execute_with(|| {
+        // Account 42 holds no funds.
+        assert!(set_profile(42, b"nobody", b"").is_err());
+        assert!(Profiles::<Test>::get(42).is_none());
+    });
+}
+
+#[test]
+fn clear_profile_releases_deposit() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(set_profile(ALICE, b"alice", b"alice#1"));
+        let deposit = held(ALICE);
+
+        assert_ok!(TeamRegistry::clear_profile(RuntimeOrigin::signed(ALICE)));
+        assert!(Profiles::<Test>::get(ALICE).is_none());
+        assert_eq!(held(ALICE), 0);
+        assert_eq!(Balances::free_balance(ALICE), 100);
+        System::assert_last_event(
+            Event::ProfileCleared {
+                who: ALICE,
+                deposit,
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            TeamRegistry::clear_profile(RuntimeOrigin::signed(ALICE)),
+            Error::<Test>::NoProfile
+        );
+    });
+}
+
+#[test]
+fn root_can_verify_profile() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            TeamRegistry::verify_profile(RuntimeOrigin::root(), ALICE),
+            Error::<Test>::NoProfile
+        );
+
+        assert_ok!(set_profile(ALICE, b"alice", b"alice#1"));
+        assert_ok!(TeamRegistry::verify_profile(RuntimeOrigin::root(), ALICE));
+        assert!(Profiles::<Test>::get(ALICE).unwrap().verified);
+        System::assert_last_event(
+            Event::ProfileVerified {
+                who: ALICE,
+                registrar: None,
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            TeamRegistry::verify_profile(RuntimeOrigin::root(), ALICE),
+            Error::<Test>::AlreadyVerified
+        );
+    });
+}
+
+#[test]
+fn registrar_can_verify_profile() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(set_profile(ALICE, b"alice", b"alice#1"));
+        assert_noop!(
+            TeamRegistry::verify_profile(RuntimeOrigin::signed(REGISTRAR), ALICE),
+            Error::<Test>::NotRegistrar
+        );
+
+        assert_ok!(TeamRegistry::add_registrar(
+            RuntimeOrigin::root(),
+            REGISTRAR
+        ));
+        assert_ok!(TeamRegistry::verify_profile(
+            RuntimeOrigin::signed(REGISTRAR),
+            ALICE
+        ));
+        assert!(Profiles::<Test>::get(ALICE).unwrap().verified);
+        System::assert_last_event(
+            Event::ProfileVerified {
+                who: ALICE,
+                registrar: Some(REGISTRAR),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn registrars_are_managed_by_registrar_origin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            TeamRegistry::add_registrar(RuntimeOrigin::signed(ALICE), REGISTRAR),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(TeamRegistry::add_registrar(
+            RuntimeOrigin::root(),
+            REGISTRAR
+        ));
+        System::assert_last_event(
+            Event::RegistrarAdded {
+                registrar: REGISTRAR,
+            }
+            .into(),
+        );
+        assert_noop!(
+            TeamRegistry::add_registrar(RuntimeOrigin::root(), REGISTRAR),
+            Error::<Test>::AlreadyRegistrar
+        );
+
+        assert_ok!(TeamRegistry::add_registrar(RuntimeOrigin::root(), BOB));
+        assert_noop!(
+            TeamRegistry::add_registrar(RuntimeOrigin::root(), ALICE),
+            Error::<Test>::TooManyRegistrars
+        );
+
+        assert_ok!(TeamRegistry::remove_registrar(
+            RuntimeOrigin::root(),
+            REGISTRAR
+        ));
+        System::assert_last_event(
+            Event::RegistrarRemoved {
+                registrar: REGISTRAR,
+            }
+            .into(),
+        );
+        assert_eq!(Registrars::<Test>::get().to_vec(), vec![BOB]);
+        assert_noop!(
+            TeamRegistry::remove_registrar(RuntimeOrigin::root(), REGISTRAR),
+            Error::<Test>::NotRegistrar
+        );
+    });
+}
+
+#[test]
+fn migrate_v0_to_v1_counts_existing_profiles() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(0).put::<TeamRegistry>();
+        for who in [ALICE, BOB] {
+            v0::Profiles::<Test>::insert(
+                who,
+                Profile {
+                    display_name: b"member".to_vec().try_into().unwrap(),
+                    email_hash: [7u8; 32],
+                    discord: Default::default(),
+                    deposit: BASIC_DEPOSIT + 6 * BYTE_DEPOSIT,
+                    verified: false,
+                },
+            );
+        }
+        assert_eq!(Profiles::<Test>::count(), 0);
+
+        MigrateV0ToV1::<Test>::on_runtime_upgrade();
+        assert_eq!(Profiles::<Test>::count(), 2);
+        assert_eq!(TeamRegistry::on_chain_storage_version(), 1);
+
+        // The migration only runs once: a profile written behind the counter's back is not
+        // counted again.
+        v0::Profiles::<Test>::insert(REGISTRAR, Profiles::<Test>::get(ALICE).unwrap());
+        MigrateV0ToV1::<Test>::on_runtime_upgrade();
+        assert_eq!(Profiles::<Test>::count(), 2);
+    });
+}
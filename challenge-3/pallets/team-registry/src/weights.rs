@@ -0,0 +1,106 @@
+//! Weights for `pallet_team_registry`.
+//!
+//! These are conservative estimates until the pallet is benchmarked on reference hardware. To
+//! regenerate them, build the node with `--features runtime-benchmarks` and run:
+//!
+//! ```sh
+//! ./target/release/parachain-template-node benchmark pallet \
+//!     --pallet pallet_team_registry --extrinsic '*' \
+//!     --steps 50 --repeat 20 \
+//!     --output pallets/team-registry/src/weights.rs
+//! ```
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_team_registry`.
+pub trait WeightInfo {
+	fn set_profile() -> Weight;
+	fn clear_profile() -> Weight;
+	fn verify_profile(r: u32, ) -> Weight;
+	fn add_registrar(r: u32, ) -> Weight;
+	fn remove_registrar(r: u32, ) -> Weight;
+}
+
+/// Weights for `pallet_team_registry` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `TeamRegistry::Profiles` (r:1 w:1)
+	/// Storage: `TeamRegistry::CounterForProfiles` (r:1 w:1)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	fn set_profile() -> Weight {
+		Weight::from_parts(45_000_000, 3691)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `TeamRegistry::Profiles` (r:1 w:1)
+	/// Storage: `TeamRegistry::CounterForProfiles` (r:1 w:1)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	fn clear_profile() -> Weight {
+		Weight::from_parts(40_000_000, 3691)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `TeamRegistry::Registrars` (r:1 w:0)
+	/// Storage: `TeamRegistry::Profiles` (r:1 w:1)
+	/// The range of component `r` is `[1, 20]`.
+	fn verify_profile(r: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 3691)
+			.saturating_add(Weight::from_parts(100_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TeamRegistry::Registrars` (r:1 w:1)
+	/// The range of component `r` is `[1, 19]`.
+	fn add_registrar(r: u32, ) -> Weight {
+		Weight::from_parts(12_000_000, 2126)
+			.saturating_add(Weight::from_parts(100_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TeamRegistry::Registrars` (r:1 w:1)
+	/// The range of component `r` is `[1, 20]`.
+	fn remove_registrar(r: u32, ) -> Weight {
+		Weight::from_parts(12_000_000, 2126)
+			.saturating_add(Weight::from_parts(100_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	fn set_profile() -> Weight {
+		Weight::from_parts(45_000_000, 3691)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn clear_profile() -> Weight {
+		Weight::from_parts(40_000_000, 3691)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn verify_profile(r: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 3691)
+			.saturating_add(Weight::from_parts(100_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn add_registrar(r: u32, ) -> Weight {
+		Weight::from_parts(12_000_000, 2126)
+			.saturating_add(Weight::from_parts(100_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn remove_registrar(r: u32, ) -> Weight {
+		Weight::from_parts(12_000_000, 2126)
+			.saturating_add(Weight::from_parts(100_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
@@ -0,0 +1,103 @@
+// This is free and unencumbered software released into the public domain.
+//
+// Anyone is free to copy, modify, publish, use, compile, sell, or
+// distribute this software, either in source code form or as a compiled
+// binary, for any purpose, commercial or non-commercial, and by any
+// means.
+//
+// In jurisdictions that recognize copyright laws, the author or authors
+// of this software dedicate any and all copyright interest in the
+// software to the public domain. We make this dedication for the benefit
+// of the public at large and to the detriment of our heirs and
+// successors. We intend this dedication to be an overt act of
+// relinquishment in perpetuity of all present and future rights to this
+// software under copyright law.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.
+// IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY CLAIM, DAMAGES OR
+// OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE,
+// ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+//
+// For more information, please refer to <http://unlicense.org>
+
+//! Benchmarking setup for pallet-xcm-trust
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as XcmTrust;
+use alloc::boxed::Box;
+use frame_benchmarking::v2::*;
+use xcm::{latest::Junction::PalletInstance, VersionedLocation};
+
+fn reserve() -> Location {
+    Location::new(1, [Parachain(1000), PalletInstance(50)])
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn trust_parachain() -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let para_id = ParaId::from(2000);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, para_id);
+
+        assert!(TrustedParachains::<T>::contains_key(para_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn distrust_parachain() -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let para_id = ParaId::from(2000);
+        TrustedParachains::<T>::insert(para_id, ());
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, para_id);
+
+        assert!(!TrustedParachains::<T>::contains_key(para_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn trust_reserve() -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(
+            origin as T::RuntimeOrigin,
+            Box::new(VersionedLocation::from(reserve())),
+        );
+
+        assert!(TrustedReserves::<T>::contains_key(reserve()));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn distrust_reserve() -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        TrustedReserves::<T>::insert(reserve(), ());
+
+        #[extrinsic_call]
+        _(
+            origin as T::RuntimeOrigin,
+            Box::new(VersionedLocation::from(reserve())),
+        );
+
+        assert!(!TrustedReserves::<T>::contains_key(reserve()));
+        Ok(())
+    }
+
+    impl_benchmark_test_suite!(XcmTrust, crate::mock::new_test_ext(), crate::mock::Test);
+}
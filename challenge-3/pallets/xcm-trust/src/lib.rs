@@ -0,0 +1,327 @@
+// This is free and unencumbered software released into the public domain.
+//
+// Anyone is free to copy, modify, publish, use, compile, sell, or
+// distribute this software, either in source code form or as a compiled
+// binary, for any purpose, commercial or non-commercial, and by any
+// means.
+//
+// In jurisdictions that recognize copyright laws, the author or authors
+// of this software dedicate any and all copyright interest in the
+// software to the public domain. We make this dedication for the benefit
+// of the public at large and to the detriment of our heirs and
+// successors. We intend this dedication to be an overt act of
+// relinquishment in perpetuity of all present and future rights to this
+// software under copyright law.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.
+// IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY CLAIM, DAMAGES OR
+// OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE,
+// ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+//
+// For more information, please refer to <http://unlicense.org>
+
+//! # XCM Trust Pallet
+//!
+//! A storage-backed allow-list of the chains this parachain trusts over XCM, so that new HRMP
+//! partners can be onboarded without a runtime upgrade.
+//!
+//! Two kinds of entries are kept, both managed by the [`Config::AdminOrigin`]:
+//!
+//! - Trusted sibling parachains may execute paid XCM programs on this chain, are accepted as the
+//!   reserve of their native asset and may receive reserve transfers from this chain.
+//! - Trusted reserve locations are accepted as the reserve of every asset under them, e.g. Asset
+//!   Hub for its trust backed assets such as USDT.
+//!
+//! The relay chain is always trusted. The allow-list is plugged into the XCM configuration through
+//! [`IsTrustedOrigin`] (barrier), [`IsTrustedReserve`] (`IsReserve`) and [`IsTrustedDestination`]
+//! (`XcmReserveTransferFilter`).
+//!
+//! ## Dispatchable Functions
+//!
+//! - [`trust_parachain`](Pallet::trust_parachain): Add a sibling parachain to the allow-list.
+//! - [`distrust_parachain`](Pallet::distrust_parachain): Remove a sibling parachain from the
+//!   allow-list.
+//! - [`trust_reserve`](Pallet::trust_reserve): Add a reserve location to the allow-list.
+//! - [`distrust_reserve`](Pallet::distrust_reserve): Remove a reserve location from the
+//!   allow-list.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use frame_support::traits::{Contains, ContainsPair};
+use xcm::latest::{Asset, AssetId, Junction::Parachain, Location};
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use alloc::boxed::Box;
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
+    pub use polkadot_parachain_primitives::primitives::Id as ParaId;
+    use xcm::VersionedLocation;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The origin allowed to change the allow-list.
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// The maximum number of trusted sibling parachains.
+        #[pallet::constant]
+        type MaxTrustedParachains: Get<u32>;
+
+        /// The maximum number of trusted reserve locations.
+        #[pallet::constant]
+        type MaxTrustedReserves: Get<u32>;
+
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    /// The trusted sibling parachains.
+    #[pallet::storage]
+    pub type TrustedParachains<T: Config> = CountedStorageMap<_, Twox64Concat, ParaId, ()>;
+
+    /// The trusted reserve locations.
+    #[pallet::storage]
+    pub type TrustedReserves<T: Config> = CountedStorageMap<_, Blake2_128Concat, Location, ()>;
+
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        /// The sibling parachains trusted at genesis.
+        pub trusted_parachains: Vec<ParaId>,
+        /// The reserve locations trusted at genesis.
+        pub trusted_reserves: Vec<Location>,
+        #[serde(skip)]
+        pub _config: PhantomData<T>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            assert!(
+                self.trusted_parachains.len() as u32 <= T::MaxTrustedParachains::get(),
+                "Too many trusted parachains at genesis"
+            );
+            assert!(
+                self.trusted_reserves.len() as u32 <= T::MaxTrustedReserves::get(),
+                "Too many trusted reserves at genesis"
+            );
+            for para_id in &self.trusted_parachains {
+                TrustedParachains::<T>::insert(para_id, ());
+            }
+            for location in &self.trusted_reserves {
+                TrustedReserves::<T>::insert(location, ());
+            }
+        }
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A sibling parachain was added to the allow-list.
+        ParachainTrusted { para_id: ParaId },
+        /// A sibling parachain was removed from the allow-list.
+        ParachainDistrusted { para_id: ParaId },
+        /// A reserve location was added to the allow-list.
+        ReserveTrusted { location: Location },
+        /// A reserve location was removed from the allow-list.
+        ReserveDistrusted { location: Location },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The parachain or location is already trusted.
+        AlreadyTrusted,
+        /// The parachain or location is not trusted.
+        NotTrusted,
+        /// There are already `MaxTrustedParachains` trusted parachains.
+        TooManyParachains,
+        /// There are already `MaxTrustedReserves` trusted reserves.
+        TooManyReserves,
+        /// The location could not be converted to the latest XCM version.
+        BadVersion,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Add the sibling parachain `para_id` to the allow-list.
+        ///
+        /// The origin must be the `AdminOrigin`.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::trust_parachain())]
+        pub fn trust_parachain(origin: OriginFor<T>, para_id: ParaId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                !TrustedParachains::<T>::contains_key(para_id),
+                Error::<T>::AlreadyTrusted
+            );
+            ensure!(
+                TrustedParachains::<T>::count() < T::MaxTrustedParachains::get(),
+                Error::<T>::TooManyParachains
+            );
+            TrustedParachains::<T>::insert(para_id, ());
+
+            Self::deposit_event(Event::ParachainTrusted { para_id });
+            Ok(())
+        }
+
+        /// Remove the sibling parachain `para_id` from the allow-list.
+        ///
+        /// The origin must be the `AdminOrigin`.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::distrust_parachain())]
+        pub fn distrust_parachain(origin: OriginFor<T>, para_id: ParaId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                TrustedParachains::<T>::contains_key(para_id),
+                Error::<T>::NotTrusted
+            );
+            TrustedParachains::<T>::remove(para_id);
+
+            Self::deposit_event(Event::ParachainDistrusted { para_id });
+            Ok(())
+        }
+
+        /// Add `location` to the allow-list of reserves.
+        ///
+        /// The origin must be the `AdminOrigin`.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::trust_reserve())]
+        pub fn trust_reserve(
+            origin: OriginFor<T>,
+            location: Box<VersionedLocation>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let location: Location = (*location).try_into().map_err(|_| Error::<T>::BadVersion)?;
+            ensure!(
+                !TrustedReserves::<T>::contains_key(&location),
+                Error::<T>::AlreadyTrusted
+            );
+            ensure!(
+                TrustedReserves::<T>::count() < T::MaxTrustedReserves::get(),
+                Error::<T>::TooManyReserves
+            );
+            TrustedReserves::<T>::insert(&location, ());
+
+            Self::deposit_event(Event::ReserveTrusted { location });
+            Ok(())
+        }
+
+        /// Remove `location` from the allow-list of reserves.
+        ///
+        /// The origin must be the `AdminOrigin`.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::distrust_reserve())]
+        pub fn distrust_reserve(
+            origin: OriginFor<T>,
+            location: Box<VersionedLocation>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let location: Location = (*location).try_into().map_err(|_| Error::<T>::BadVersion)?;
+            ensure!(
+                TrustedReserves::<T>::contains_key(&location),
+                Error::<T>::NotTrusted
+            );
+            TrustedReserves::<T>::remove(&location);
+
+            Self::deposit_event(Event::ReserveDistrusted { location });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Whether `location` is the relay chain or a trusted sibling parachain.
+        pub fn is_trusted_chain(location: &Location) -> bool {
+            match location.unpack() {
+                (1, []) => true,
+                (1, [Parachain(id)]) => TrustedParachains::<T>::contains_key(ParaId::from(*id)),
+                _ => false,
+            }
+        }
+
+        /// Whether `location` is a trusted reserve.
+        pub fn is_trusted_reserve(location: &Location) -> bool {
+            TrustedReserves::<T>::contains_key(location)
+        }
+    }
+}
+
+/// Accepts the locations allowed to execute paid XCM programs on this chain: the relay chain, the
+/// trusted sibling parachains and the trusted reserves, as well as anything under them (e.g. their
+/// accounts).
+pub struct IsTrustedOrigin<T>(PhantomData<T>);
+impl<T: Config> Contains<Location> for IsTrustedOrigin<T> {
+    fn contains(location: &Location) -> bool {
+        match location.unpack() {
+            (1, []) => true,
+            (1, [Parachain(id), ..]) if TrustedParachains::<T>::contains_key(ParaId::from(*id)) => {
+                true
+            }
+            _ => {
+                let mut prefix = location.clone();
+                loop {
+                    if Pallet::<T>::is_trusted_reserve(&prefix) {
+                        return true;
+                    }
+                    if prefix.take_last().is_none() {
+                        return false;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Accepts reserve transferred assets when `origin` is their reserve: the relay chain and the
+/// trusted sibling parachains for their native asset, and the trusted reserves for every asset
+/// under them.
+pub struct IsTrustedReserve<T>(PhantomData<T>);
+impl<T: Config> ContainsPair<Asset, Location> for IsTrustedReserve<T> {
+    fn contains(asset: &Asset, origin: &Location) -> bool {
+        let AssetId(id) = &asset.id;
+        (id == origin && Pallet::<T>::is_trusted_chain(origin))
+            || (id.starts_with(origin) && Pallet::<T>::is_trusted_reserve(origin))
+    }
+}
+
+/// Accepts the destinations this chain may reserve transfer assets to: the trusted sibling
+/// parachains and the trusted reserves.
+pub struct IsTrustedDestination<T>(PhantomData<T>);
+impl<T: Config> Contains<(Location, Vec<Asset>)> for IsTrustedDestination<T> {
+    fn contains((dest, _): &(Location, Vec<Asset>)) -> bool {
+        match dest.unpack() {
+            (1, [Parachain(id)]) if TrustedParachains::<T>::contains_key(ParaId::from(*id)) => true,
+            _ => Pallet::<T>::is_trusted_reserve(dest),
+        }
+    }
+}
@@ -0,0 +1,242 @@
+// This is free and unencumbered software released into the public domain.
+//
+// Anyone is free to copy, modify, publish, use, compile, sell, or
+// distribute this software, either in source code form or as a compiled
+// binary, for any purpose, commercial or non-commercial, and by any
+// means.
+//
+// In jurisdictions that recognize copyright laws, the author or authors
+// of this software dedicate any and all copyright interest in the
+// software to the public domain. We make this dedication for the benefit
+// of the public at large and to the detriment of our heirs and
+// successors. We intend this dedication to be an overt act of
+// relinquishment in perpetuity of all present and future rights to this
+// software under copyright law.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.
+// IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY CLAIM, DAMAGES OR
+// OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE,
+// ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+//
+// For more information, please refer to <http://unlicense.org>
+
+use crate::{
+    mock::*, Error, Event, IsTrustedDestination, IsTrustedOrigin, IsTrustedReserve, ParaId,
+    TrustedParachains, TrustedReserves,
+};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{Contains, ContainsPair},
+};
+use sp_runtime::DispatchError;
+use xcm::{
+    latest::prelude::{AccountId32, Asset, GeneralIndex, Location, PalletInstance, Parachain},
+    VersionedLocation,
+};
+
+fn sibling() -> Location {
+    Location::new(1, [Parachain(SIBLING)])
+}
+
+fn asset_hub() -> Location {
+    Location::new(1, [Parachain(ASSET_HUB)])
+}
+
+fn usdt() -> Location {
+    Location::new(
+        1,
+        [Parachain(ASSET_HUB), PalletInstance(50), GeneralIndex(1984)],
+    )
+}
+
+fn asset(id: Location) -> Asset {
+    (id, 1_000u128).into()
+}
+
+fn versioned(location: Location) -> Box<VersionedLocation> {
+    Box::new(location.into())
+}
+
+#[test]
+fn trust_parachain_works() {
+    new_test_ext().execute_with(|| {
+        let para_id = ParaId::from(SIBLING);
+        assert_ok!(XcmTrust::trust_parachain(RuntimeOrigin::root(), para_id));
+        assert!(TrustedParachains::<Test>::contains_key(para_id));
+        System::assert_last_event(Event::ParachainTrusted { para_id }.into());
+
+        assert_noop!(
+            XcmTrust::trust_parachain(RuntimeOrigin::root(), para_id),
+            Error::<Test>::AlreadyTrusted
+        );
+
+        assert_ok!(XcmTrust::distrust_parachain(RuntimeOrigin::root(), para_id));
+        assert!(!TrustedParachains::<Test>::contains_key(para_id));
+        System::assert_last_event(Event::ParachainDistrusted { para_id }.into());
+
+        assert_noop!(
+            XcmTrust::distrust_parachain(RuntimeOrigin::root(), para_id),
+            Error::<Test>::NotTrusted
+        );
+    });
+}
+
+#[test]
+fn trust_parachain_is_bounded() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(XcmTrust::trust_parachain(
+            RuntimeOrigin::root(),
+            2001.into()
+        ));
+        assert_ok!(XcmTrust::trust_parachain(
+            RuntimeOrigin::root(),
+            2002.into()
+        ));
+        assert_noop!(
+            XcmTrust::trust_parachain(RuntimeOrigin::root(), 2003.into()),
+            Error::<Test>::TooManyParachains
+        );
+    });
+}
+
+#[test]
+fn trust_reserve_works() {
+    new_test_ext().execute_with(|| {
+        assert!(TrustedReserves::<Test>::contains_key(asset_hub()));
+        assert_noop!(
+            XcmTrust::trust_reserve(RuntimeOrigin::root(), versioned(asset_hub())),
+            Error::<Test>::AlreadyTrusted
+        );
+
+        assert_ok!(XcmTrust::trust_reserve(
+            RuntimeOrigin::root(),
+            versioned(sibling())
+        ));
+        System::assert_last_event(
+            Event::ReserveTrusted {
+                location: sibling(),
+            }
+            .into(),
+        );
+        assert_noop!(
+            XcmTrust::trust_reserve(RuntimeOrigin::root(), versioned(Location::parent())),
+            Error::<Test>::TooManyReserves
+        );
+
+        assert_ok!(XcmTrust::distrust_reserve(
+            RuntimeOrigin::root(),
+            versioned(asset_hub())
+        ));
+        assert!(!TrustedReserves::<Test>::contains_key(asset_hub()));
+        System::assert_last_event(
+            Event::ReserveDistrusted {
+                location: asset_hub(),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn changes_require_admin_origin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            XcmTrust::trust_parachain(RuntimeOrigin::signed(1), SIBLING.into()),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            XcmTrust::distrust_reserve(RuntimeOrigin::signed(1), versioned(asset_hub())),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn trusted_origins() {
+    new_test_ext().execute_with(|| {
+        let sibling_account = Location::new(
+            1,
+            [
+                Parachain(SIBLING),
+                AccountId32 {
+                    network: None,
+                    id: [1; 32],
+                },
+            ],
+        );
+
+        assert!(IsTrustedOrigin::<Test>::contains(&Location::parent()));
+        assert!(IsTrustedOrigin::<Test>::contains(&asset_hub()));
+        assert!(!IsTrustedOrigin::<Test>::contains(&sibling()));
+        assert!(!IsTrustedOrigin::<Test>::contains(&sibling_account));
+
+        assert_ok!(XcmTrust::trust_parachain(
+            RuntimeOrigin::root(),
+            SIBLING.into()
+        ));
+        assert!(IsTrustedOrigin::<Test>::contains(&sibling()));
+        assert!(IsTrustedOrigin::<Test>::contains(&sibling_account));
+    });
+}
+
+#[test]
+fn trusted_reserves() {
+    new_test_ext().execute_with(|| {
+        // The relay chain is the reserve of its native asset.
+        let relay = Location::parent();
+        assert!(IsTrustedReserve::<Test>::contains(
+            &asset(relay.clone()),
+            &relay
+        ));
+        // Asset Hub is a trusted reserve for all of its assets.
+        assert!(IsTrustedReserve::<Test>::contains(
+            &asset(usdt()),
+            &asset_hub()
+        ));
+        // But not for the assets of other chains.
+        assert!(!IsTrustedReserve::<Test>::contains(
+            &asset(relay),
+            &asset_hub()
+        ));
+        // Siblings are only the reserve of their native asset once trusted.
+        assert!(!IsTrustedReserve::<Test>::contains(
+            &asset(sibling()),
+            &sibling()
+        ));
+        assert_ok!(XcmTrust::trust_parachain(
+            RuntimeOrigin::root(),
+            SIBLING.into()
+        ));
+        assert!(IsTrustedReserve::<Test>::contains(
+            &asset(sibling()),
+            &sibling()
+        ));
+        assert!(!IsTrustedReserve::<Test>::contains(
+            &asset(usdt()),
+            &sibling()
+        ));
+    });
+}
+
+#[test]
+fn trusted_destinations() {
+    new_test_ext().execute_with(|| {
+        assert!(IsTrustedDestination::<Test>::contains(&(
+            asset_hub(),
+            vec![asset(usdt())]
+        )));
+        assert!(!IsTrustedDestination::<Test>::contains(&(
+            sibling(),
+            vec![]
+        )));
+
+        assert_ok!(XcmTrust::trust_parachain(
+            RuntimeOrigin::root(),
+            SIBLING.into()
+        ));
+        assert!(IsTrustedDestination::<Test>::contains(&(sibling(), vec![])));
+    });
+}
@@ -0,0 +1,84 @@
+//! Weights for `pallet_xcm_trust`.
+//!
+//! These are conservative estimates until the pallet is benchmarked on reference hardware. To
+//! regenerate them, build the node with `--features runtime-benchmarks` and run:
+//!
+//! ```sh
+//! ./target/release/parachain-template-node benchmark pallet \
+//!     --pallet pallet_xcm_trust --extrinsic '*' \
+//!     --steps 50 --repeat 20 \
+//!     --output pallets/xcm-trust/src/weights.rs
+//! ```
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_xcm_trust`.
+pub trait WeightInfo {
+	fn trust_parachain() -> Weight;
+	fn distrust_parachain() -> Weight;
+	fn trust_reserve() -> Weight;
+	fn distrust_reserve() -> Weight;
+}
+
+/// Weights for `pallet_xcm_trust` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `XcmTrust::TrustedParachains` (r:1 w:1)
+	/// Storage: `XcmTrust::CounterForTrustedParachains` (r:1 w:1)
+	fn trust_parachain() -> Weight {
+		Weight::from_parts(15_000_000, 1493)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `XcmTrust::TrustedParachains` (r:1 w:1)
+	/// Storage: `XcmTrust::CounterForTrustedParachains` (r:1 w:1)
+	fn distrust_parachain() -> Weight {
+		Weight::from_parts(15_000_000, 1493)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `XcmTrust::TrustedReserves` (r:1 w:1)
+	/// Storage: `XcmTrust::CounterForTrustedReserves` (r:1 w:1)
+	fn trust_reserve() -> Weight {
+		Weight::from_parts(20_000_000, 4162)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `XcmTrust::TrustedReserves` (r:1 w:1)
+	/// Storage: `XcmTrust::CounterForTrustedReserves` (r:1 w:1)
+	fn distrust_reserve() -> Weight {
+		Weight::from_parts(20_000_000, 4162)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	fn trust_parachain() -> Weight {
+		Weight::from_parts(15_000_000, 1493)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn distrust_parachain() -> Weight {
+		Weight::from_parts(15_000_000, 1493)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn trust_reserve() -> Weight {
+		Weight::from_parts(20_000_000, 4162)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn distrust_reserve() -> Weight {
+		Weight::from_parts(20_000_000, 4162)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
@@ -1,11 +1,15 @@
+use std::path::Path;
+
 use cumulus_primitives_core::ParaId;
-use parachain_template_runtime as runtime;
-use runtime::{AccountId, AuraId, Signature, EXISTENTIAL_DEPOSIT};
+use parachain_from_scratch_runtime as runtime;
+use runtime::{AccountId, AuraId, Balance, Signature, EXISTENTIAL_DEPOSIT};
 use sc_chain_spec::{ChainSpecExtension, ChainSpecGroup};
+use sc_network::config::MultiaddrWithPeerId;
 use sc_service::ChainType;
 use serde::{Deserialize, Serialize};
-use sp_core::{sr25519, Pair, Public};
-use sp_runtime::traits::{IdentifyAccount, Verify};
+use sp_core::{crypto::Ss58Codec, sr25519, Pair, Public};
+use sp_runtime::traits::{AccountIdConversion, IdentifyAccount, Verify};
+use xcm::latest::{Junction::Parachain, Location};
 
 /// Specialized `ChainSpec` for the normal parachain runtime.
 pub type ChainSpec = sc_service::GenericChainSpec<Extensions>;
@@ -13,6 +17,25 @@ pub type ChainSpec = sc_service::GenericChainSpec<Extensions>;
 /// The default XCM version to set in genesis config.
 const SAFE_XCM_VERSION: u32 = xcm::prelude::XCM_VERSION;
 
+/// The para ID of Asset Hub, trusted at genesis as the reserve of its assets.
+const ASSET_HUB_PARA_ID: u32 = 1000;
+
+/// Seeds of the well-known development accounts, whose secret keys are public.
+const DEV_SEEDS: [&str; 12] = [
+    "Alice",
+    "Bob",
+    "Charlie",
+    "Dave",
+    "Eve",
+    "Ferdie",
+    "Alice//stash",
+    "Bob//stash",
+    "Charlie//stash",
+    "Dave//stash",
+    "Eve//stash",
+    "Ferdie//stash",
+];
+
 /// Helper function to generate a crypto pair from seed
 pub fn get_from_seed<TPublic: Public>(seed: &str) -> <TPublic::Pair as Pair>::Public {
     TPublic::Pair::from_string(&format!("//{}", seed), None)
@@ -55,6 +78,25 @@ where
     AccountPublic::from(get_from_seed::<TPublic>(seed)).into_account()
 }
 
+/// Whether `account` is one of the well-known development accounts.
+pub fn is_dev_account(account: &AccountId) -> bool {
+    DEV_SEEDS
+        .iter()
+        .any(|seed| get_account_id_from_seed::<sr25519::Public>(seed) == *account)
+}
+
+/// Whether `key` is the Aura key of one of the well-known development accounts.
+pub fn is_dev_aura_key(key: &AuraId) -> bool {
+    DEV_SEEDS
+        .iter()
+        .any(|seed| get_collator_keys_from_seed(seed) == *key)
+}
+
+/// Whether chains of type `chain_type` may use development keys.
+pub fn is_dev_chain_type(chain_type: &ChainType) -> bool {
+    matches!(chain_type, ChainType::Development | ChainType::Local)
+}
+
 /// Generate the session keys from individual elements.
 ///
 /// The input must be a tuple of individual keys (a single arg for now since we have just one key).
@@ -171,16 +213,40 @@ fn testnet_genesis(
     root: AccountId,
     id: ParaId,
 ) -> serde_json::Value {
+    let mut genesis = collator_genesis(invulnerables, id);
+    genesis["balances"] =
+        balances_genesis(endowed_accounts.into_iter().map(|k| (k, 1 << 60)).collect());
+    genesis["sudo"] = serde_json::json!({ "key": Some(root) });
+    genesis
+}
+
+/// Genesis configuration of `pallet_balances`, endowing `endowments`.
+fn balances_genesis(endowments: Vec<(AccountId, Balance)>) -> serde_json::Value {
+    // The collators' share of the fees is usually below the existential deposit, so the pot that
+    // collects it has to exist from genesis.
+    let pot: AccountId = runtime::PotId::get().into_account_truncating();
+    serde_json::json!({
+        "balances": endowments
+            .into_iter()
+            .chain([(pot, EXISTENTIAL_DEPOSIT)])
+            .collect::<Vec<_>>(),
+    })
+}
+
+/// Genesis configuration of the pallets that tie the chain to a relay chain: the para ID, the
+/// invulnerable collators with their session keys, the terms of collator candidacy, the XCM
+/// version and Asset Hub as a trusted reserve.
+///
+/// This holds no balances or privileged keys, so it can be laid under an existing chain state.
+pub fn collator_genesis(invulnerables: Vec<(AccountId, AuraId)>, id: ParaId) -> serde_json::Value {
     serde_json::json!({
-        "balances": {
-            "balances": endowed_accounts.iter().cloned().map(|k| (k, 1u64 << 60)).collect::<Vec<_>>(),
-        },
         "parachainInfo": {
             "parachainId": id,
         },
         "collatorSelection": {
             "invulnerables": invulnerables.iter().cloned().map(|(acc, _)| acc).collect::<Vec<_>>(),
             "candidacyBond": EXISTENTIAL_DEPOSIT * 16,
+            "desiredCandidates": 20,
         },
         "session": {
             "keys": invulnerables
@@ -197,6 +263,190 @@ fn testnet_genesis(
         "polkadotXcm": {
             "safeXcmVersion": Some(SAFE_XCM_VERSION),
         },
-        "sudo": { "key": Some(root) }
+        "xcmTrust": {
+            "trustedReserves": [
+                Location::new(1, [Parachain(ASSET_HUB_PARA_ID)]),
+            ],
+        },
     })
 }
+
+/// Configuration of a live chain, from which [`live_config`] builds its chain spec.
+///
+/// Keys and accounts are given in SS58 or as 32 hex encoded bytes, so that no secret ever needs to
+/// be known to build the chain spec.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct LiveChainConfig {
+    /// Name of the chain.
+    pub name: String,
+    /// Identifier of the chain, which also names its database directory.
+    pub id: String,
+    /// Type of the chain, `Live` by default.
+    #[serde(default = "live_chain_type")]
+    pub chain_type: ChainType,
+    /// Network protocol identifier.
+    pub protocol_id: Option<String>,
+    /// The para ID reserved on the relay chain.
+    pub para_id: u32,
+    /// The relay chain the parachain is registered on.
+    pub relay_chain: String,
+    /// Symbol of the native token.
+    pub token_symbol: String,
+    /// Number of decimals of the native token.
+    pub token_decimals: u8,
+    /// SS58 prefix of the addresses of the chain, 42 by default.
+    #[serde(default = "generic_ss58_format")]
+    pub ss58_format: u16,
+    /// Boot nodes, as multiaddresses ending with `/p2p/<PEER_ID>`.
+    #[serde(default)]
+    pub boot_nodes: Vec<String>,
+    /// Invulnerable collators of the chain.
+    pub collators: Vec<LiveCollator>,
+    /// Accounts endowed at genesis.
+    #[serde(default)]
+    pub endowments: Vec<LiveEndowment>,
+    /// Sudo key, if the chain has one.
+    pub sudo: Option<String>,
+    /// Initial members of the council.
+    #[serde(default)]
+    pub council: Vec<String>,
+}
+
+/// A collator of a live chain.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct LiveCollator {
+    /// Account of the collator.
+    pub account: String,
+    /// Aura key of the collator.
+    pub aura: String,
+}
+
+/// An account endowed at the genesis of a live chain.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct LiveEndowment {
+    /// The endowed account.
+    pub account: String,
+    /// Its free balance, in the smallest unit of the native token.
+    pub balance: Balance,
+}
+
+fn live_chain_type() -> ChainType {
+    ChainType::Live
+}
+
+fn generic_ss58_format() -> u16 {
+    42
+}
+
+/// Build the chain spec of a live or staging chain from the [`LiveChainConfig`] in `path`.
+///
+/// Development keys are refused unless the chain type is `Development` or `Local`.
+pub fn live_config(path: &Path) -> Result<ChainSpec, String> {
+    let file = std::fs::File::open(path)
+        .map_err(|e| format!("Error opening chain config `{}`: {}", path.display(), e))?;
+    let config: LiveChainConfig = serde_json::from_reader(file)
+        .map_err(|e| format!("Error parsing chain config `{}`: {}", path.display(), e))?;
+
+    let collators = config
+        .collators
+        .iter()
+        .map(|collator| Ok((parse_key(&collator.account)?, parse_key(&collator.aura)?)))
+        .collect::<Result<Vec<(AccountId, AuraId)>, String>>()?;
+    let endowments = config
+        .endowments
+        .iter()
+        .map(|endowment| Ok((parse_key(&endowment.account)?, endowment.balance)))
+        .collect::<Result<Vec<(AccountId, Balance)>, String>>()?;
+    let sudo = config.sudo.as_deref().map(parse_key).transpose()?;
+    let council = config
+        .council
+        .iter()
+        .map(String::as_str)
+        .map(parse_key)
+        .collect::<Result<Vec<AccountId>, String>>()?;
+    let boot_nodes = config
+        .boot_nodes
+        .iter()
+        .map(|node| {
+            node.parse::<MultiaddrWithPeerId>()
+                .map_err(|e| format!("Invalid boot node `{}`: {}", node, e))
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    if collators.is_empty() {
+        return Err("At least one collator is needed to produce blocks".into());
+    }
+    if sudo.is_none() && council.is_empty() {
+        return Err("Either a sudo key or council members are needed to govern the chain".into());
+    }
+    if !is_dev_chain_type(&config.chain_type) {
+        let mut accounts = collators
+            .iter()
+            .map(|(account, _)| account)
+            .chain(endowments.iter().map(|(account, _)| account))
+            .chain(sudo.iter())
+            .chain(council.iter());
+        if let Some(account) = accounts.find(|account| is_dev_account(account)) {
+            return Err(format!(
+                "{} is a development account, whose secret key is public",
+                account.to_ss58check()
+            ));
+        }
+        if let Some((_, aura)) = collators.iter().find(|(_, aura)| is_dev_aura_key(aura)) {
+            return Err(format!(
+                "{} is a development Aura key, whose secret key is public",
+                aura.to_ss58check()
+            ));
+        }
+    }
+
+    let mut properties = sc_chain_spec::Properties::new();
+    properties.insert("tokenSymbol".into(), config.token_symbol.into());
+    properties.insert("tokenDecimals".into(), config.token_decimals.into());
+    properties.insert("ss58Format".into(), config.ss58_format.into());
+
+    let mut genesis = collator_genesis(collators, config.para_id.into());
+    genesis["balances"] = balances_genesis(endowments);
+    if let Some(sudo) = sudo {
+        genesis["sudo"] = serde_json::json!({ "key": Some(sudo) });
+    }
+    if !council.is_empty() {
+        genesis["council"] = serde_json::json!({ "members": council });
+    }
+
+    let mut builder = ChainSpec::builder(
+        runtime::WASM_BINARY.ok_or("WASM binary was not built, please build it!")?,
+        Extensions {
+            relay_chain: config.relay_chain,
+            para_id: config.para_id,
+        },
+    )
+    .with_name(&config.name)
+    .with_id(&config.id)
+    .with_chain_type(config.chain_type)
+    .with_boot_nodes(boot_nodes)
+    .with_properties(properties)
+    .with_genesis_config_patch(genesis);
+    if let Some(protocol_id) = &config.protocol_id {
+        builder = builder.with_protocol_id(protocol_id);
+    }
+    Ok(builder.build())
+}
+
+/// Parse a key or an account ID given in SS58 or as hex encoded bytes.
+pub fn parse_key<T>(s: &str) -> Result<T, String>
+where
+    T: Ss58Codec + for<'a> TryFrom<&'a [u8]>,
+{
+    let key = if s.starts_with("0x") {
+        sp_core::bytes::from_hex(s)
+            .ok()
+            .and_then(|bytes| T::try_from(&bytes[..]).ok())
+    } else {
+        T::from_ss58check(s).ok()
+    };
+    key.ok_or_else(|| format!("Invalid key `{}`, expected SS58 or hex encoded bytes", s))
+}
@@ -34,6 +34,18 @@ pub enum Subcommand {
     /// Export the genesis wasm of the parachain.
     ExportGenesisWasm(cumulus_client_cli::ExportGenesisWasmCommand),
 
+    /// Export everything needed to register the parachain on its relay chain into a directory.
+    ExportRegistration(crate::registration::ExportRegistrationCmd),
+
+    /// Convert the state exported from the solochain node into a parachain genesis chain spec.
+    ConvertSolochainState(crate::solochain_migration::ConvertSolochainStateCmd),
+
+    /// Check a chain specification for common mistakes, reporting all of them.
+    ValidateSpec(crate::spec_validation::ValidateSpecCmd),
+
+    /// Generate new session keys on a running collator, and optionally register them on chain.
+    RotateSessionKeys(crate::session_keys::RotateSessionKeysCmd),
+
     /// Sub-commands concerned with benchmarking.
     /// The pallet benchmarking moved to the `pallet` sub-command.
     #[command(subcommand)]
@@ -76,6 +88,15 @@ pub struct Cli {
     #[arg(long)]
     pub no_hardware_benchmarks: bool,
 
+    /// Run without a relay chain, sealing a block every given number of milliseconds, or as soon
+    /// as a transaction enters the pool with `0`.
+    ///
+    /// The relay chain data of each block is mocked. Blocks can also be sealed with
+    /// `engine_createBlock`, and inbound XCM messages injected with `dev_injectDownwardMessage`
+    /// and `dev_injectHorizontalMessage`. Relay chain arguments are ignored.
+    #[arg(long, value_name = "MILLISECONDS")]
+    pub dev_block_time: Option<u64>,
+
     /// Relay chain arguments
     #[arg(raw = true)]
     pub relay_chain_args: Vec<String>,
@@ -4,7 +4,7 @@ use cumulus_client_service::storage_proof_size::HostFunctions as ReclaimHostFunc
 use cumulus_primitives_core::ParaId;
 use frame_benchmarking_cli::{BenchmarkCmd, SUBSTRATE_REFERENCE_HARDWARE};
 use log::info;
-use parachain_template_runtime::Block;
+use parachain_from_scratch_runtime::Block;
 use sc_cli::{
     ChainSpec, CliConfiguration, DefaultConfigurationValues, ImportParams, KeystoreParams,
     NetworkParams, Result, SharedParams, SubstrateCli,
@@ -22,9 +22,12 @@ fn load_spec(id: &str) -> std::result::Result<Box<dyn ChainSpec>, String> {
         "dev" => Box::new(chain_spec::development_config()),
         "template-rococo" => Box::new(chain_spec::local_testnet_config()),
         "" | "local" => Box::new(chain_spec::local_testnet_config()),
-        path => Box::new(chain_spec::ChainSpec::from_json_file(
-            std::path::PathBuf::from(path),
-        )?),
+        id => match id.strip_prefix("live:") {
+            Some(config) => Box::new(chain_spec::live_config(std::path::Path::new(config))?),
+            None => Box::new(chain_spec::ChainSpec::from_json_file(
+                std::path::PathBuf::from(id),
+            )?),
+        },
     })
 }
 
@@ -181,6 +184,26 @@ pub fn run() -> Result<()> {
                 cmd.run(&*spec)
             })
         }
+        Some(Subcommand::ExportRegistration(cmd)) => {
+            let runner = cli.create_runner(cmd)?;
+            runner.sync_run(|config| {
+                let partials = new_partial(&config)?;
+
+                cmd.run(&*config.chain_spec, &*partials.client)
+            })
+        }
+        Some(Subcommand::ConvertSolochainState(cmd)) => {
+            let runner = cli.create_runner(cmd)?;
+            runner.sync_run(|_config| cmd.run())
+        }
+        Some(Subcommand::ValidateSpec(cmd)) => {
+            let runner = cli.create_runner(cmd)?;
+            runner.sync_run(|config| cmd.run(&*config.chain_spec))
+        }
+        Some(Subcommand::RotateSessionKeys(cmd)) => {
+            let runner = cli.create_runner(cmd)?;
+            runner.sync_run(|_config| cmd.run())
+        }
         Some(Subcommand::Benchmark(cmd)) => {
             let runner = cli.create_runner(cmd)?;
             // Switch on the concrete benchmark sub-command-
@@ -225,6 +248,19 @@ pub fn run() -> Result<()> {
             let collator_options = cli.run.collator_options();
 
             runner.run_node_until_exit(|config| async move {
+                let para_id = chain_spec::Extensions::try_get(&*config.chain_spec)
+                    .map(|e| e.para_id)
+                    .ok_or("Could not find parachain ID in chain-spec.")?;
+
+                if let Some(block_time) = cli.dev_block_time {
+                    return crate::service::start_dev_node(
+                        config,
+                        ParaId::from(para_id),
+                        block_time,
+                    )
+                    .map_err(Into::into);
+                }
+
                 let hwbench = (!cli.no_hardware_benchmarks)
                     .then_some(config.database.path().map(|database_path| {
                         let _ = std::fs::create_dir_all(database_path);
@@ -232,10 +268,6 @@ pub fn run() -> Result<()> {
                     }))
                     .flatten();
 
-                let para_id = chain_spec::Extensions::try_get(&*config.chain_spec)
-                    .map(|e| e.para_id)
-                    .ok_or("Could not find parachain ID in chain-spec.")?;
-
                 let polkadot_cli = RelayChainCli::new(
                     &config,
                     [RelayChainCli::executable_name()]
@@ -0,0 +1,79 @@
+//! Messages injected into a parachain running without a relay chain.
+//!
+//! In development mode the relay chain is mocked, so nothing sends downward or horizontal
+//! messages to the parachain. The `dev_*` RPC methods queue messages that are delivered through
+//! the mocked parachain inherent of the next block.
+
+#![warn(missing_docs)]
+
+use std::sync::{Arc, Mutex};
+
+use cumulus_primitives_core::ParaId;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use sp_core::Bytes;
+
+/// Messages waiting for the next block.
+#[derive(Default)]
+pub struct PendingMessages {
+    /// Downward messages from the relay chain.
+    pub downward: Vec<Vec<u8>>,
+    /// Horizontal messages, with the sibling parachain that sent them.
+    pub horizontal: Vec<(ParaId, Vec<u8>)>,
+}
+
+/// Messages shared between the RPC server and the block authoring task.
+pub type SharedPendingMessages = Arc<Mutex<PendingMessages>>;
+
+/// Takes the messages to deliver in the block being built.
+pub fn take_pending_messages(messages: &SharedPendingMessages) -> PendingMessages {
+    std::mem::take(
+        &mut *messages
+            .lock()
+            .expect("no panic while holding the lock; qed"),
+    )
+}
+
+/// Injection of inbound XCM messages in development mode.
+#[rpc(server)]
+pub trait DevApi {
+    /// Queues a downward message, a SCALE encoded `VersionedXcm`, for the next block.
+    #[method(name = "dev_injectDownwardMessage")]
+    fn inject_downward_message(&self, message: Bytes) -> RpcResult<()>;
+
+    /// Queues a horizontal message from parachain `sender` for the next block. The message is in
+    /// the XCMP format, starting with its `XcmpMessageFormat`.
+    #[method(name = "dev_injectHorizontalMessage")]
+    fn inject_horizontal_message(&self, sender: u32, message: Bytes) -> RpcResult<()>;
+}
+
+/// Implementation of [`DevApiServer`].
+pub struct Dev {
+    messages: SharedPendingMessages,
+}
+
+impl Dev {
+    /// Creates the RPC handler, queuing messages into `messages`.
+    pub fn new(messages: SharedPendingMessages) -> Self {
+        Self { messages }
+    }
+}
+
+impl DevApiServer for Dev {
+    fn inject_downward_message(&self, message: Bytes) -> RpcResult<()> {
+        self.messages
+            .lock()
+            .expect("no panic while holding the lock; qed")
+            .downward
+            .push(message.0);
+        Ok(())
+    }
+
+    fn inject_horizontal_message(&self, sender: u32, message: Bytes) -> RpcResult<()> {
+        self.messages
+            .lock()
+            .expect("no panic while holding the lock; qed")
+            .horizontal
+            .push((sender.into(), message.0));
+        Ok(())
+    }
+}
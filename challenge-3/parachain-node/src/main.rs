@@ -5,8 +5,13 @@
 mod chain_spec;
 mod cli;
 mod command;
+mod dev;
+mod registration;
 mod rpc;
 mod service;
+mod session_keys;
+mod solochain_migration;
+mod spec_validation;
 
 fn main() -> sc_cli::Result<()> {
     command::run()
@@ -0,0 +1,132 @@
+//! Everything needed to register the parachain on a relay chain, exported in one go.
+//!
+//! Registering a parachain takes its genesis head, its validation code and the para ID reserved
+//! on the relay chain. The `export-registration` command writes them into a directory together
+//! with the encoded `registrar.register` call and a `manifest.json` describing the bundle.
+
+use std::{fs, path::PathBuf};
+
+use codec::Encode;
+use cumulus_primitives_core::{
+    relay_chain::{HeadData, ValidationCode},
+    ParaId,
+};
+use log::info;
+use parachain_from_scratch_runtime::opaque::Block;
+use sc_cli::{CliConfiguration, SharedParams};
+use serde::Serialize;
+use sp_blockchain::HeaderBackend;
+use sp_core::{blake2_256, hexdisplay::HexDisplay};
+
+use crate::chain_spec::Extensions;
+
+/// File holding the hex encoded genesis head.
+const GENESIS_HEAD_FILE: &str = "genesis-head";
+/// File holding the hex encoded validation code.
+const VALIDATION_CODE_FILE: &str = "genesis-wasm";
+/// File holding the hex encoded `registrar.register` call.
+const REGISTER_CALL_FILE: &str = "register-call";
+/// File describing the bundle.
+const MANIFEST_FILE: &str = "manifest.json";
+
+/// Index of `register` in the calls of the relay chain `registrar` pallet.
+const REGISTER_CALL_INDEX: u8 = 0;
+
+/// The `export-registration` command used to export the registration bundle of the parachain.
+#[derive(Debug, clap::Parser)]
+pub struct ExportRegistrationCmd {
+    /// Directory to write the bundle into. It is created if it does not exist.
+    #[arg(value_name = "DIRECTORY")]
+    pub output: PathBuf,
+
+    /// Index of the `registrar` pallet in the relay chain runtime.
+    ///
+    /// It is 70 on Polkadot, Kusama, Paseo and Rococo.
+    #[arg(long, default_value_t = 70)]
+    pub registrar_pallet_index: u8,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub shared_params: SharedParams,
+}
+
+/// Content of `manifest.json`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Manifest<'a> {
+    chain: &'a str,
+    relay_chain: &'a str,
+    para_id: u32,
+    genesis_head: &'a str,
+    validation_code: &'a str,
+    validation_code_hash: String,
+    register_call: &'a str,
+}
+
+impl ExportRegistrationCmd {
+    /// Run the `export-registration` command.
+    pub fn run(
+        &self,
+        chain_spec: &dyn sc_service::ChainSpec,
+        client: &impl HeaderBackend<Block>,
+    ) -> sc_cli::Result<()> {
+        let extensions =
+            Extensions::try_get(chain_spec).ok_or("The chain spec has no parachain extensions")?;
+
+        let genesis_head = client
+            .header(client.info().genesis_hash)?
+            .ok_or("The genesis block is not in the database")?
+            .encode();
+        let validation_code = cumulus_client_cli::extract_genesis_wasm(chain_spec)?;
+        let validation_code_hash = blake2_256(&validation_code);
+        let register_call = (
+            self.registrar_pallet_index,
+            REGISTER_CALL_INDEX,
+            ParaId::from(extensions.para_id),
+            HeadData(genesis_head.clone()),
+            ValidationCode(validation_code.clone()),
+        )
+            .encode();
+
+        let manifest = Manifest {
+            chain: chain_spec.id(),
+            relay_chain: &extensions.relay_chain,
+            para_id: extensions.para_id,
+            genesis_head: GENESIS_HEAD_FILE,
+            validation_code: VALIDATION_CODE_FILE,
+            validation_code_hash: hex(&validation_code_hash),
+            register_call: REGISTER_CALL_FILE,
+        };
+
+        fs::create_dir_all(&self.output)?;
+        fs::write(self.output.join(GENESIS_HEAD_FILE), hex(&genesis_head))?;
+        fs::write(
+            self.output.join(VALIDATION_CODE_FILE),
+            hex(&validation_code),
+        )?;
+        fs::write(self.output.join(REGISTER_CALL_FILE), hex(&register_call))?;
+        fs::write(
+            self.output.join(MANIFEST_FILE),
+            serde_json::to_string_pretty(&manifest)
+                .map_err(|e| format!("Failed to serialize the manifest: {}", e))?,
+        )?;
+
+        info!(
+            "Exported the registration of para {} on `{}` into {}",
+            extensions.para_id,
+            extensions.relay_chain,
+            self.output.display()
+        );
+        Ok(())
+    }
+}
+
+impl CliConfiguration for ExportRegistrationCmd {
+    fn shared_params(&self) -> &SharedParams {
+        &self.shared_params
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    format!("0x{}", HexDisplay::from(&bytes))
+}
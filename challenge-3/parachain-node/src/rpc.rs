@@ -7,13 +7,14 @@
 
 use std::sync::Arc;
 
-use parachain_template_runtime::{opaque::Block, AccountId, Balance, Nonce};
+use parachain_from_scratch_runtime::{opaque::Block, AccountId, Balance, Nonce};
 
 pub use sc_rpc::DenyUnsafe;
 use sc_transaction_pool_api::TransactionPool;
 use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
+use sp_keystore::KeystorePtr;
 
 /// A type representing all RPC extensions.
 pub type RpcExtension = jsonrpsee::RpcModule<()>;
@@ -24,6 +25,8 @@ pub struct FullDeps<C, P> {
     pub client: Arc<C>,
     /// Transaction pool instance.
     pub pool: Arc<P>,
+    /// Keystore of the node, where rotated session keys are stored.
+    pub keystore: KeystorePtr,
     /// Whether to deny unsafe calls
     pub deny_unsafe: DenyUnsafe,
 }
@@ -41,9 +44,13 @@ where
         + 'static,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
+    C::Api: chain_stats_rpc::ChainStatsRuntimeApi<Block, Balance>,
+    C::Api: sp_session::SessionKeys<Block>,
     C::Api: BlockBuilder<Block>,
     P: TransactionPool + Sync + Send + 'static,
 {
+    use crate::session_keys::{SessionKeysApiServer, SessionKeysRpc};
+    use chain_stats_rpc::{ChainStatsApiServer, ChainStatsRpc};
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
     use substrate_frame_rpc_system::{System, SystemApiServer};
 
@@ -51,10 +58,13 @@ where
     let FullDeps {
         client,
         pool,
+        keystore,
         deny_unsafe,
     } = deps;
 
     module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
-    module.merge(TransactionPayment::new(client).into_rpc())?;
+    module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+    module.merge(ChainStatsRpc::<_, Block, Balance>::new(client.clone()).into_rpc())?;
+    module.merge(SessionKeysRpc::new(client, keystore, deny_unsafe).into_rpc())?;
     Ok(module)
 }
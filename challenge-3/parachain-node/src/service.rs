@@ -5,7 +5,7 @@ use std::{sync::Arc, time::Duration};
 
 use cumulus_client_cli::CollatorOptions;
 // Local Runtime Types
-use parachain_template_runtime::{
+use parachain_from_scratch_runtime::{
     apis::RuntimeApi,
     opaque::{Block, Hash},
 };
@@ -15,28 +15,43 @@ use cumulus_client_collator::service::CollatorService;
 use cumulus_client_consensus_aura::collators::lookahead::{self as aura, Params as AuraParams};
 use cumulus_client_consensus_common::ParachainBlockImport as TParachainBlockImport;
 use cumulus_client_consensus_proposer::Proposer;
+use cumulus_client_parachain_inherent::{MockValidationDataInherentDataProvider, MockXcmConfig};
 use cumulus_client_service::{
     build_network, build_relay_chain_interface, prepare_node_config, start_relay_chain_tasks,
     BuildNetworkParams, CollatorSybilResistance, DARecoveryProfile, ParachainHostFunctions,
     StartRelayChainTasksParams,
 };
 use cumulus_primitives_core::{
-    relay_chain::{CollatorPair, ValidationCode},
+    relay_chain::{self, CollatorPair, HeadData, ValidationCode},
     ParaId,
 };
 use cumulus_relay_chain_interface::{OverseerHandle, RelayChainInterface};
 
+// Local
+use crate::dev::{Dev, DevApiServer};
+
 // Substrate Imports
+use codec::{Decode, Encode};
 use frame_benchmarking_cli::SUBSTRATE_REFERENCE_HARDWARE;
+use futures::{channel::mpsc, StreamExt};
 use prometheus_endpoint::Registry;
-use sc_client_api::Backend;
+use sc_client_api::{Backend, StorageProvider};
 use sc_consensus::ImportQueue;
+use sc_consensus_manual_seal::{
+    consensus::aura::AuraConsensusDataProvider,
+    rpc::{ManualSeal, ManualSealApiServer},
+    EngineCommand, ManualSealParams,
+};
 use sc_executor::{HeapAllocStrategy, WasmExecutor, DEFAULT_HEAP_ALLOC_STRATEGY};
-use sc_network::NetworkBlock;
+use sc_network::{NetworkBackend, NetworkBlock};
 use sc_network_sync::SyncingService;
 use sc_service::{Configuration, PartialComponents, TFullBackend, TFullClient, TaskManager};
 use sc_telemetry::{Telemetry, TelemetryHandle, TelemetryWorker, TelemetryWorkerHandle};
-use sc_transaction_pool_api::OffchainTransactionPoolFactory;
+use sc_transaction_pool_api::{OffchainTransactionPoolFactory, TransactionPool};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_consensus_aura::{AuraApi, Slot};
+use sp_core::{storage::StorageKey, twox_128};
 use sp_keystore::KeystorePtr;
 
 #[docify::export(wasm_executor)]
@@ -48,6 +63,15 @@ type ParachainBackend = TFullBackend<Block>;
 
 type ParachainBlockImport = TParachainBlockImport<Block, Arc<ParachainClient>, ParachainBackend>;
 
+/// Builds the queue importing the blocks of the node.
+type BuildImportQueue = fn(
+    Arc<ParachainClient>,
+    ParachainBlockImport,
+    &Configuration,
+    Option<TelemetryHandle>,
+    &TaskManager,
+) -> sc_consensus::DefaultImportQueue<Block>;
+
 /// Assembly of PartialComponents (enough to run chain ops subcommands)
 pub type Service = PartialComponents<
     ParachainClient,
@@ -68,6 +92,14 @@ pub type Service = PartialComponents<
 /// be able to perform chain operations.
 #[docify::export(component_instantiation)]
 pub fn new_partial(config: &Configuration) -> Result<Service, sc_service::Error> {
+    new_partial_with_import_queue(config, build_import_queue)
+}
+
+/// The parts of [`new_partial`], importing blocks through the queue built by `build_import_queue`.
+fn new_partial_with_import_queue(
+    config: &Configuration,
+    build_import_queue: BuildImportQueue,
+) -> Result<Service, sc_service::Error> {
     let telemetry = config
         .telemetry_endpoints
         .clone()
@@ -168,6 +200,21 @@ fn build_import_queue(
     )
 }
 
+/// Build the import queue of a node sealing its own blocks, which trusts every block it imports.
+fn build_dev_import_queue(
+    client: Arc<ParachainClient>,
+    _block_import: ParachainBlockImport,
+    config: &Configuration,
+    _telemetry: Option<TelemetryHandle>,
+    task_manager: &TaskManager,
+) -> sc_consensus::DefaultImportQueue<Block> {
+    sc_consensus_manual_seal::import_queue(
+        Box::new(client),
+        &task_manager.spawn_essential_handle(),
+        config.prometheus_registry(),
+    )
+}
+
 #[allow(clippy::too_many_arguments)]
 fn start_consensus(
     client: Arc<ParachainClient>,
@@ -319,11 +366,13 @@ pub async fn start_parachain_node(
     let rpc_builder = {
         let client = client.clone();
         let transaction_pool = transaction_pool.clone();
+        let keystore = params.keystore_container.keystore();
 
         Box::new(move |deny_unsafe, _| {
             let deps = crate::rpc::FullDeps {
                 client: client.clone(),
                 pool: transaction_pool.clone(),
+                keystore: keystore.clone(),
                 deny_unsafe,
             };
 
@@ -423,3 +472,204 @@ pub async fn start_parachain_node(
 
     Ok((task_manager, client))
 }
+
+/// Start a node that runs the parachain runtime without a relay chain, for development.
+///
+/// Blocks are sealed by this node alone: every `block_time` milliseconds, or as soon as a
+/// transaction enters the pool when `block_time` is zero, as well as on `engine_createBlock`.
+/// The relay chain data of each block is mocked, relay parents advance by one block per
+/// parachain block, and messages queued through the `dev_*` RPC methods are delivered as if they
+/// came from the relay chain or a sibling parachain.
+#[sc_tracing::logging::prefix_logs_with("Parachain")]
+pub fn start_dev_node(
+    config: Configuration,
+    para_id: ParaId,
+    block_time: u64,
+) -> sc_service::error::Result<TaskManager> {
+    let params = new_partial_with_import_queue(&config, build_dev_import_queue)?;
+    let (_, mut telemetry, _) = params.other;
+    let net_config = sc_network::config::FullNetworkConfiguration::<
+        _,
+        _,
+        sc_network::NetworkWorker<Block, Hash>,
+    >::new(&config.network);
+    let metrics = sc_network::NetworkWorker::<Block, Hash>::register_notification_metrics(
+        config.prometheus_registry(),
+    );
+
+    let client = params.client.clone();
+    let backend = params.backend.clone();
+    let mut task_manager = params.task_manager;
+    let transaction_pool = params.transaction_pool.clone();
+    let prometheus_registry = config.prometheus_registry().cloned();
+
+    let (network, system_rpc_tx, tx_handler_controller, start_network, sync_service) =
+        sc_service::build_network(sc_service::BuildNetworkParams {
+            config: &config,
+            net_config,
+            client: client.clone(),
+            transaction_pool: transaction_pool.clone(),
+            spawn_handle: task_manager.spawn_handle(),
+            import_queue: params.import_queue,
+            block_announce_validator_builder: None,
+            warp_sync_params: None,
+            block_relay: None,
+            metrics,
+        })?;
+
+    // Requests from `engine_createBlock` and `engine_finalizeBlock`.
+    let (command_sink, rpc_commands) = mpsc::channel(1024);
+    let pending_messages = crate::dev::SharedPendingMessages::default();
+
+    let rpc_builder = {
+        let client = client.clone();
+        let transaction_pool = transaction_pool.clone();
+        let keystore = params.keystore_container.keystore();
+        let pending_messages = pending_messages.clone();
+
+        Box::new(move |deny_unsafe, _| {
+            let deps = crate::rpc::FullDeps {
+                client: client.clone(),
+                pool: transaction_pool.clone(),
+                keystore: keystore.clone(),
+                deny_unsafe,
+            };
+
+            let build = || -> Result<_, Box<dyn std::error::Error + Send + Sync>> {
+                let mut module = crate::rpc::create_full(deps)?;
+                module.merge(ManualSeal::new(command_sink.clone()).into_rpc())?;
+                module.merge(Dev::new(pending_messages.clone()).into_rpc())?;
+                Ok(module)
+            };
+
+            build().map_err(Into::into)
+        })
+    };
+
+    sc_service::spawn_tasks(sc_service::SpawnTasksParams {
+        rpc_builder,
+        client: client.clone(),
+        transaction_pool: transaction_pool.clone(),
+        task_manager: &mut task_manager,
+        config,
+        keystore: params.keystore_container.keystore(),
+        backend: backend.clone(),
+        network,
+        sync_service,
+        system_rpc_tx,
+        tx_handler_controller,
+        telemetry: telemetry.as_mut(),
+    })?;
+
+    let seal_new_block = |create_empty| EngineCommand::SealNewBlock {
+        create_empty,
+        finalize: true,
+        parent_hash: None,
+        sender: None,
+    };
+    let commands_stream = if block_time == 0 {
+        futures::stream::select(
+            rpc_commands,
+            transaction_pool
+                .import_notification_stream()
+                .map(move |_| seal_new_block(false)),
+        )
+        .boxed()
+    } else {
+        futures::stream::select(
+            rpc_commands,
+            futures::stream::unfold((), move |()| async move {
+                futures_timer::Delay::new(Duration::from_millis(block_time)).await;
+                Some((seal_new_block(true), ()))
+            }),
+        )
+        .boxed()
+    };
+
+    let proposer_factory = sc_basic_authorship::ProposerFactory::with_proof_recording(
+        task_manager.spawn_handle(),
+        client.clone(),
+        transaction_pool.clone(),
+        prometheus_registry.as_ref(),
+        telemetry.as_ref().map(|t| t.handle()),
+    );
+
+    let slot_duration = client
+        .runtime_api()
+        .slot_duration(client.info().best_hash)
+        .map_err(|e| sc_service::Error::Application(Box::new(e)))?;
+    let inherent_client = client.clone();
+
+    let authorship_future = sc_consensus_manual_seal::run_manual_seal(ManualSealParams {
+        block_import: client.clone(),
+        env: proposer_factory,
+        client: client.clone(),
+        pool: transaction_pool,
+        commands_stream,
+        select_chain: sc_consensus::LongestChain::new(backend),
+        consensus_data_provider: Some(Box::new(AuraConsensusDataProvider::new(client))),
+        create_inherent_data_providers: move |parent_hash, ()| {
+            let client = inherent_client.clone();
+            let pending_messages = crate::dev::take_pending_messages(&pending_messages);
+            async move {
+                let parent = client
+                    .header(parent_hash)?
+                    .ok_or("the parent of a sealed block is known; qed")?;
+
+                // Blocks can be sealed faster than the slot duration, while Aura requires every
+                // block to be in a later slot than its parent.
+                let timestamp = sp_timestamp::Timestamp::current()
+                    .as_millis()
+                    .max(parent_timestamp(&client, parent_hash) + slot_duration.as_millis());
+                let slot = Slot::from_timestamp(timestamp.into(), slot_duration);
+
+                let parachain_inherent = MockValidationDataInherentDataProvider {
+                    current_para_block: parent.number,
+                    para_id,
+                    current_para_block_head: Some(HeadData(parent.encode())),
+                    relay_offset: 1000,
+                    relay_blocks_per_para_block: 1,
+                    para_blocks_per_relay_epoch: 10,
+                    relay_randomness_config: (),
+                    xcm_config: MockXcmConfig::new(&*client, parent_hash, Default::default()),
+                    raw_downward_messages: pending_messages.downward,
+                    raw_horizontal_messages: pending_messages.horizontal,
+                    // The relay chain and parachain slots have the same duration, and the
+                    // consensus hook of the runtime checks that they match.
+                    additional_key_values: Some(vec![(
+                        relay_chain::well_known_keys::CURRENT_SLOT.to_vec(),
+                        slot.encode(),
+                    )]),
+                };
+
+                Ok::<_, Box<dyn std::error::Error + Send + Sync>>((
+                    sp_timestamp::InherentDataProvider::new(timestamp.into()),
+                    sp_consensus_aura::inherents::InherentDataProvider::new(slot),
+                    parachain_inherent,
+                ))
+            }
+        },
+    });
+
+    task_manager.spawn_essential_handle().spawn_blocking(
+        "manual-seal",
+        Some("block-authoring"),
+        authorship_future,
+    );
+
+    start_network.start_network();
+
+    Ok(task_manager)
+}
+
+/// The timestamp of block `hash` in milliseconds, or zero for the genesis block.
+fn parent_timestamp(client: &ParachainClient, hash: Hash) -> u64 {
+    // `pallet_timestamp::Now`
+    let key = StorageKey([twox_128(b"Timestamp"), twox_128(b"Now")].concat());
+    client
+        .storage(hash, &key)
+        .ok()
+        .flatten()
+        .and_then(|now| u64::decode(&mut &now.0[..]).ok())
+        .unwrap_or_default()
+}
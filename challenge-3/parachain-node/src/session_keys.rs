@@ -0,0 +1,239 @@
+//! Rotation of the session keys of a collator.
+//!
+//! `session_rotateKeys` generates fresh keys in the keystore of the node, like `author_rotateKeys`,
+//! and also returns the `session.set_keys` call that registers them on chain. The
+//! `rotate-session-keys` command calls it on a running collator, and can sign and submit that call
+//! from the collator account.
+
+#![warn(missing_docs)]
+
+use std::sync::Arc;
+
+use codec::{Decode, Encode};
+use jsonrpsee::{
+    core::{client::ClientT, RpcResult},
+    proc_macros::rpc,
+    rpc_params,
+    types::error::{ErrorObject, ErrorObjectOwned},
+    ws_client::WsClientBuilder,
+};
+use log::info;
+use parachain_from_scratch_runtime::{
+    self as runtime, opaque::Block, AccountId, Hash, Nonce, Runtime, RuntimeCall,
+};
+use sc_cli::{CliConfiguration, SharedParams};
+use sc_rpc::DenyUnsafe;
+use serde::{Deserialize, Serialize};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::{crypto::Ss58Codec, hexdisplay::HexDisplay, sr25519, Bytes, Pair};
+use sp_keystore::{KeystoreExt, KeystorePtr};
+use sp_runtime::generic::Era;
+use sp_session::SessionKeys;
+
+/// Error code of a failed runtime call.
+const RUNTIME_ERROR: i32 = 1;
+
+/// Session keys freshly generated in the keystore of the node.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RotatedKeys {
+    /// The SCALE encoded `SessionKeys`.
+    pub keys: Bytes,
+    /// The SCALE encoded `session.set_keys` call registering `keys` for the signer.
+    pub set_keys_call: Bytes,
+}
+
+/// Rotation of the session keys of the node.
+#[rpc(client, server)]
+pub trait SessionKeysApi {
+    /// Generates new session keys in the keystore and returns them with the call setting them.
+    #[method(name = "session_rotateKeys")]
+    fn rotate_keys(&self) -> RpcResult<RotatedKeys>;
+}
+
+/// Implementation of [`SessionKeysApiServer`], generating the keys through the runtime.
+pub struct SessionKeysRpc<C> {
+    client: Arc<C>,
+    keystore: KeystorePtr,
+    deny_unsafe: DenyUnsafe,
+}
+
+impl<C> SessionKeysRpc<C> {
+    /// Creates the RPC handler, storing the keys in `keystore`.
+    pub fn new(client: Arc<C>, keystore: KeystorePtr, deny_unsafe: DenyUnsafe) -> Self {
+        Self {
+            client,
+            keystore,
+            deny_unsafe,
+        }
+    }
+}
+
+impl<C> SessionKeysApiServer for SessionKeysRpc<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: SessionKeys<Block>,
+{
+    fn rotate_keys(&self) -> RpcResult<RotatedKeys> {
+        self.deny_unsafe.check_if_safe()?;
+
+        let best_hash = self.client.info().best_hash;
+        let mut runtime_api = self.client.runtime_api();
+        runtime_api.register_extension(KeystoreExt::from(self.keystore.clone()));
+        let keys = runtime_api
+            .generate_session_keys(best_hash, None)
+            .map_err(|e| runtime_error(e.to_string()))?;
+
+        let session_keys = runtime::SessionKeys::decode(&mut &keys[..])
+            .map_err(|e| runtime_error(e.to_string()))?;
+        let call = RuntimeCall::Session(pallet_session::Call::set_keys {
+            keys: session_keys,
+            proof: vec![],
+        });
+
+        Ok(RotatedKeys {
+            keys: keys.into(),
+            set_keys_call: call.encode().into(),
+        })
+    }
+}
+
+fn runtime_error(message: String) -> ErrorObjectOwned {
+    ErrorObject::owned(
+        RUNTIME_ERROR,
+        "Unable to generate session keys.",
+        Some(message),
+    )
+}
+
+/// The `rotate-session-keys` command, rotating the session keys of a running collator.
+#[derive(Debug, clap::Parser)]
+pub struct RotateSessionKeysCmd {
+    /// RPC endpoint of the collator. Unsafe RPC methods must be allowed on it.
+    #[arg(long, default_value = "ws://127.0.0.1:9944")]
+    pub rpc_url: String,
+
+    /// Secret URI of the collator account.
+    ///
+    /// When given, `session.set_keys` is signed with this account and submitted through the
+    /// collator. Otherwise only the new keys are printed.
+    #[arg(long)]
+    pub suri: Option<String>,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub shared_params: SharedParams,
+}
+
+/// The fields of the runtime version that transactions are signed against.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RuntimeVersion {
+    spec_version: u32,
+    transaction_version: u32,
+}
+
+impl RotateSessionKeysCmd {
+    /// Run the `rotate-session-keys` command.
+    pub fn run(&self) -> sc_cli::Result<()> {
+        sc_cli::build_runtime()?
+            .block_on(self.rotate())
+            .map_err(|e| format!("Rotating the session keys failed: {}", e).into())
+    }
+
+    async fn rotate(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let client = WsClientBuilder::default().build(&self.rpc_url).await?;
+
+        let rotated = client.rotate_keys().await?;
+        println!("0x{}", HexDisplay::from(&rotated.keys.0));
+
+        let Some(suri) = &self.suri else {
+            return Ok(());
+        };
+        let signer = sr25519::Pair::from_string(suri, None)
+            .map_err(|e| format!("invalid secret URI: {:?}", e))?;
+        let account = AccountId::from(signer.public());
+        let call = RuntimeCall::decode(&mut &rotated.set_keys_call[..])?;
+
+        let nonce: Nonce = client
+            .request(
+                "system_accountNextIndex",
+                rpc_params![account.to_ss58check()],
+            )
+            .await?;
+        let genesis_hash: Option<Hash> =
+            client.request("chain_getBlockHash", rpc_params![0]).await?;
+        let genesis_hash = genesis_hash.ok_or("the node has no genesis block")?;
+        let version: RuntimeVersion = client
+            .request("state_getRuntimeVersion", rpc_params![])
+            .await?;
+
+        let extrinsic = signed_extrinsic(call, &signer, nonce, genesis_hash, version);
+        let hash: Hash = client
+            .request(
+                "author_submitExtrinsic",
+                rpc_params![Bytes(extrinsic.encode())],
+            )
+            .await?;
+        info!(
+            "Submitted session.set_keys for {} in transaction {:?}",
+            account.to_ss58check(),
+            hash
+        );
+        Ok(())
+    }
+}
+
+impl CliConfiguration for RotateSessionKeysCmd {
+    fn shared_params(&self) -> &SharedParams {
+        &self.shared_params
+    }
+}
+
+/// Sign `call` by `signer`, as an immortal transaction paying its fee in the native token.
+fn signed_extrinsic(
+    call: RuntimeCall,
+    signer: &sr25519::Pair,
+    nonce: Nonce,
+    genesis_hash: Hash,
+    version: RuntimeVersion,
+) -> runtime::UncheckedExtrinsic {
+    let extra: runtime::SignedExtra = (
+        frame_system::CheckNonZeroSender::<Runtime>::new(),
+        frame_system::CheckSpecVersion::<Runtime>::new(),
+        frame_system::CheckTxVersion::<Runtime>::new(),
+        frame_system::CheckGenesis::<Runtime>::new(),
+        frame_system::CheckEra::<Runtime>::from(Era::Immortal),
+        frame_system::CheckNonce::<Runtime>::from(nonce),
+        frame_system::CheckWeight::<Runtime>::new(),
+        pallet_asset_conversion_tx_payment::ChargeAssetTxPayment::<Runtime>::from(0, None),
+        cumulus_primitives_storage_weight_reclaim::StorageWeightReclaim::<Runtime>::new(),
+        frame_metadata_hash_extension::CheckMetadataHash::<Runtime>::new(false),
+    );
+
+    let payload = runtime::SignedPayload::from_raw(
+        call.clone(),
+        extra.clone(),
+        (
+            (),
+            version.spec_version,
+            version.transaction_version,
+            genesis_hash,
+            genesis_hash,
+            (),
+            (),
+            (),
+            (),
+            None,
+        ),
+    );
+    let signature = payload.using_encoded(|e| signer.sign(e));
+
+    runtime::UncheckedExtrinsic::new_signed(
+        call,
+        AccountId::from(signer.public()).into(),
+        runtime::Signature::Sr25519(signature),
+        extra,
+    )
+}
@@ -0,0 +1,359 @@
+//! Conversion of a solochain state export into a parachain genesis.
+//!
+//! The `solo-node export-state` command dumps the raw storage of a block into a chain spec. This
+//! module lays that storage over the genesis of the parachain runtime: balances, the sudo key and
+//! the storage of any custom pallet are carried over, while consensus related state (Aura and
+//! Grandpa authorities, sessions, ...) is dropped and rebuilt for the given collators.
+
+use std::{collections::BTreeSet, fs, io::Write, path::PathBuf};
+
+use codec::{Decode, Encode};
+use cumulus_primitives_core::ParaId;
+use log::info;
+use parachain_from_scratch_runtime::{AccountId, AuraId, Balance, Nonce};
+use sc_cli::{CliConfiguration, SharedParams};
+use sc_service::ChainSpec as _;
+use sp_core::{crypto::Ss58Codec, storage::Storage, twox_128};
+use sp_runtime::BuildStorage;
+
+use crate::chain_spec::{self, ChainSpec, Extensions};
+
+type AccountInfo = frame_system::AccountInfo<Nonce, pallet_balances::AccountData<Balance>>;
+
+/// Pallets whose solochain state is discarded, because the parachain genesis rebuilds it.
+///
+/// `System::Account` is the exception, see [`is_migrated`].
+const REGENERATED_PALLETS: &[&str] = &[
+    "System",
+    "Timestamp",
+    "TransactionPayment",
+    "Authorship",
+    "Session",
+    "Aura",
+    "Grandpa",
+    "ParachainSystem",
+    "ParachainInfo",
+    "CollatorSelection",
+    "AuraExt",
+    "XcmpQueue",
+    "PolkadotXcm",
+    "CumulusXcm",
+    "MessageQueue",
+    "XcmTrust",
+    "ForeignAssets",
+];
+
+/// The `convert-solochain-state` command used to turn a solochain state export into a parachain
+/// genesis chain spec.
+#[derive(Debug, clap::Parser)]
+pub struct ConvertSolochainStateCmd {
+    /// Chain spec produced by `export-state` on the solochain node.
+    #[arg(value_name = "SOLOCHAIN_STATE")]
+    pub input: PathBuf,
+
+    /// Output file name, or stdout if unspecified.
+    #[arg(long, short)]
+    pub output: Option<PathBuf>,
+
+    /// The id of the parachain.
+    #[arg(long, default_value_t = 2000)]
+    pub para_id: u32,
+
+    /// The relay chain the parachain is going to be registered on.
+    #[arg(long, default_value = "rococo-local")]
+    pub relay_chain: String,
+
+    /// Collator of the parachain, as `<ACCOUNT>:<AURA_KEY>` in SS58 format.
+    ///
+    /// The collators become the invulnerables of the chain and have their session keys set at
+    /// genesis. Can be passed multiple times.
+    #[arg(long = "collator", value_name = "ACCOUNT:AURA_KEY", value_parser = parse_collator, required = true)]
+    pub collators: Vec<(AccountId, AuraId)>,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub shared_params: SharedParams,
+}
+
+impl ConvertSolochainStateCmd {
+    /// Run the `convert-solochain-state` command.
+    pub fn run(&self) -> sc_cli::Result<()> {
+        let solochain_spec: sc_service::GenericChainSpec =
+            sc_service::GenericChainSpec::from_json_file(self.input.clone())?;
+        let solochain_storage = solochain_spec.build_storage()?;
+
+        let mut parachain_spec = ChainSpec::builder(
+            parachain_from_scratch_runtime::WASM_BINARY
+                .ok_or("WASM binary was not built, please build it!")?,
+            Extensions {
+                relay_chain: self.relay_chain.clone(),
+                para_id: self.para_id,
+            },
+        )
+        .with_name(solochain_spec.name())
+        .with_id(solochain_spec.id())
+        .with_chain_type(solochain_spec.chain_type())
+        .with_properties(solochain_spec.properties())
+        .with_genesis_config_patch(chain_spec::collator_genesis(
+            self.collators.clone(),
+            ParaId::from(self.para_id),
+        ))
+        .build();
+        let mut storage = parachain_spec.build_storage()?;
+
+        let migrated = migrate_storage(solochain_storage, &mut storage)?;
+        info!(
+            "Migrated {} storage entries from `{}` into parachain {}",
+            migrated,
+            solochain_spec.name(),
+            self.para_id
+        );
+
+        parachain_spec.set_storage(storage);
+        let json = sc_service::chain_ops::build_spec(&parachain_spec, true)?;
+        match &self.output {
+            Some(path) => fs::write(path, json)?,
+            None => std::io::stdout().write_all(json.as_bytes())?,
+        }
+        Ok(())
+    }
+}
+
+impl CliConfiguration for ConvertSolochainStateCmd {
+    fn shared_params(&self) -> &SharedParams {
+        &self.shared_params
+    }
+}
+
+/// Parse a `<ACCOUNT>:<AURA_KEY>` pair of SS58 encoded keys.
+fn parse_collator(s: &str) -> Result<(AccountId, AuraId), String> {
+    let (account, aura) = s
+        .split_once(':')
+        .ok_or_else(|| format!("expected `<ACCOUNT>:<AURA_KEY>`, got `{}`", s))?;
+    let account = AccountId::from_ss58check(account)
+        .map_err(|e| format!("invalid collator account `{}`: {:?}", account, e))?;
+    let aura = AuraId::from_ss58check(aura)
+        .map_err(|e| format!("invalid Aura key `{}`: {:?}", aura, e))?;
+    Ok((account, aura))
+}
+
+/// Whether the solochain value stored under `key` is carried over to the parachain.
+fn is_migrated(key: &[u8]) -> bool {
+    // Well known keys such as `:code` always belong to the parachain runtime.
+    if key.starts_with(b":") || key.len() < 16 {
+        return false;
+    }
+    if key.starts_with(&system_account_prefix()) {
+        return true;
+    }
+    !REGENERATED_PALLETS
+        .iter()
+        .any(|pallet| key[..16] == twox_128(pallet.as_bytes()))
+}
+
+/// Storage prefix of the `System::Account` map.
+fn system_account_prefix() -> [u8; 32] {
+    let mut prefix = [0u8; 32];
+    prefix[..16].copy_from_slice(&twox_128(b"System"));
+    prefix[16..].copy_from_slice(&twox_128(b"Account"));
+    prefix
+}
+
+/// Storage prefix of the `Session::NextKeys` map.
+fn session_next_keys_prefix() -> [u8; 32] {
+    let mut prefix = [0u8; 32];
+    prefix[..16].copy_from_slice(&twox_128(b"Session"));
+    prefix[16..].copy_from_slice(&twox_128(b"NextKeys"));
+    prefix
+}
+
+/// Accounts that registered session keys on the solochain.
+fn session_key_owners(solochain: &Storage) -> Result<BTreeSet<AccountId>, codec::Error> {
+    let prefix = session_next_keys_prefix();
+    solochain
+        .top
+        .keys()
+        .filter(|key| key.starts_with(&prefix))
+        // `NextKeys` is a `Twox64Concat` map, the account follows the 8 bytes of its hash.
+        .map(|key| AccountId::decode(&mut &key[prefix.len() + 8..]))
+        .collect()
+}
+
+/// Lay the migrated part of `solochain` over the parachain genesis `storage`.
+///
+/// Only the keys found in `solochain` are written, so the entries of the parachain genesis that
+/// the solochain does not have are kept. Returns the number of migrated entries.
+fn migrate_storage(solochain: Storage, storage: &mut Storage) -> Result<usize, String> {
+    let account_prefix = system_account_prefix();
+    let solochain_key_owners =
+        session_key_owners(&solochain).map_err(|e| format!("invalid session keys: {}", e))?;
+    let collators =
+        session_key_owners(storage).map_err(|e| format!("invalid session keys: {}", e))?;
+
+    let mut migrated = 0;
+    for (key, value) in solochain
+        .top
+        .into_iter()
+        .filter(|(key, _)| is_migrated(key))
+    {
+        let value = if key.starts_with(&account_prefix) {
+            migrate_account(
+                &key,
+                &value,
+                storage.top.get(&key),
+                &solochain_key_owners,
+                &collators,
+            )
+            .map_err(|e| format!("invalid account: {}", e))?
+        } else {
+            value
+        };
+        storage.top.insert(key, value);
+        migrated += 1;
+    }
+
+    for (key, child) in solochain.children_default {
+        migrated += child.data.len();
+        storage.children_default.insert(key, child);
+    }
+
+    Ok(migrated)
+}
+
+/// Migrate the solochain account stored under `key`.
+///
+/// The consumer reference held by the session keys of the account is released, since the
+/// `Session` state is discarded. An account that also exists in the parachain genesis (e.g. a
+/// collator that already held funds on the solochain) keeps its solochain data, along with the
+/// reference counters set up by the parachain genesis.
+///
+/// The parachain genesis gives collators without funds a provider for their session keys instead
+/// of a consumer. A collator funded on the solochain is provided for by its funds, so its keys
+/// hold a consumer reference like those of a collator endowed at genesis.
+fn migrate_account(
+    key: &[u8],
+    solochain: &[u8],
+    genesis: Option<&Vec<u8>>,
+    solochain_key_owners: &BTreeSet<AccountId>,
+    collators: &BTreeSet<AccountId>,
+) -> Result<Vec<u8>, codec::Error> {
+    let mut account = AccountInfo::decode(&mut &solochain[..])?;
+    // `Account` is a `Blake2_128Concat` map, the account follows the 16 bytes of its hash.
+    let who = AccountId::decode(&mut &key[system_account_prefix().len() + 16..])?;
+    if solochain_key_owners.contains(&who) {
+        account.consumers = account.consumers.saturating_sub(1);
+    }
+    if let Some(genesis) = genesis {
+        let genesis = AccountInfo::decode(&mut &genesis[..])?;
+        let genesis_consumers = if collators.contains(&who) {
+            genesis.consumers.max(1)
+        } else {
+            genesis.consumers
+        };
+        account.consumers = account.consumers.saturating_add(genesis_consumers);
+        account.providers = account.providers.max(genesis.providers);
+        account.sufficients = account.sufficients.max(genesis.sufficients);
+    }
+    Ok(account.encode())
+}
+
+#[cfg(test)]
+mod tests {
+    use parachain_from_scratch_runtime::{ParachainInfo, Runtime, RuntimeGenesisConfig};
+    use parachain_from_scratch_solochain_runtime as solochain;
+    use sp_keyring::Sr25519Keyring::{self, Alice, Bob, Charlie};
+
+    use super::*;
+
+    const ENDOWMENT: Balance = 1 << 60;
+
+    /// The state of a development solochain, on which Alice and Bob are validators.
+    fn solochain_state() -> Storage {
+        let validator = |keyring: Sr25519Keyring| {
+            let account = keyring.to_account_id();
+            let keys = solochain::SessionKeys {
+                aura: keyring.public().into(),
+            };
+            (account.clone(), account, keys)
+        };
+
+        solochain::RuntimeGenesisConfig {
+            balances: solochain::BalancesConfig {
+                balances: vec![
+                    (Alice.to_account_id(), ENDOWMENT),
+                    (Bob.to_account_id(), ENDOWMENT),
+                ],
+            },
+            session: solochain::SessionConfig {
+                keys: vec![validator(Alice), validator(Bob)],
+                ..Default::default()
+            },
+            sudo: solochain::SudoConfig {
+                key: Some(Alice.to_account_id()),
+            },
+            ..Default::default()
+        }
+        .build_storage()
+        .unwrap()
+    }
+
+    /// The genesis of parachain 2000, collated by Bob and Charlie.
+    fn parachain_genesis() -> Storage {
+        let collators = vec![
+            (Bob.to_account_id(), Bob.public().into()),
+            (Charlie.to_account_id(), Charlie.public().into()),
+        ];
+        let mut config = serde_json::to_value(RuntimeGenesisConfig::default()).unwrap();
+        sc_chain_spec::json_merge(
+            &mut config,
+            chain_spec::collator_genesis(collators, ParaId::from(2000)),
+        );
+        serde_json::from_value::<RuntimeGenesisConfig>(config)
+            .unwrap()
+            .build_storage()
+            .unwrap()
+    }
+
+    #[test]
+    fn dev_solochain_state_is_migrated() {
+        let mut storage = parachain_genesis();
+        migrate_storage(solochain_state(), &mut storage).unwrap();
+
+        sp_io::TestExternalities::new(storage).execute_with(|| {
+            // The solochain balances are carried over, and still add up to the total issuance.
+            let balances = |who: Sr25519Keyring| {
+                pallet_balances::Pallet::<Runtime>::free_balance(who.to_account_id())
+            };
+            assert_eq!(balances(Alice), ENDOWMENT);
+            assert_eq!(balances(Bob), ENDOWMENT);
+            let total: Balance = frame_system::Account::<Runtime>::iter_values()
+                .map(|account| account.data.free)
+                .sum();
+            assert_eq!(pallet_balances::TotalIssuance::<Runtime>::get(), total);
+
+            // The parachain genesis is kept where the solochain has nothing to migrate.
+            assert_eq!(u32::from(ParachainInfo::parachain_id()), 2000);
+            assert_eq!(
+                pallet_collator_selection::Invulnerables::<Runtime>::get().into_inner(),
+                vec![Bob.to_account_id(), Charlie.to_account_id()]
+            );
+
+            // Only the session keys of the collators remain. Bob is funded, so his keys hold a
+            // consumer reference, while Charlie's keys provide for his empty account as they do on
+            // a fresh chain.
+            let next_keys = |who: Sr25519Keyring| {
+                pallet_session::NextKeys::<Runtime>::contains_key(who.to_account_id())
+            };
+            assert!(!next_keys(Alice));
+            assert!(next_keys(Bob));
+            assert!(next_keys(Charlie));
+            let references = |who: Sr25519Keyring| {
+                let account = frame_system::Account::<Runtime>::get(who.to_account_id());
+                (account.consumers, account.providers)
+            };
+            assert_eq!(references(Alice), (0, 1));
+            assert_eq!(references(Bob), (1, 1));
+            assert_eq!(references(Charlie), (0, 1));
+        });
+    }
+}
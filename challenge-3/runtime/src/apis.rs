@@ -40,29 +40,16 @@ use sp_runtime::{
 use sp_std::prelude::Vec;
 use sp_version::RuntimeVersion;
 
-// TODO: Remove when migrating to a parachain
-use crate::Grandpa;
-
 // Local module imports
 use super::{
-    AccountId,
-    Balance,
-    Block,
-    // ConsensusHook,
-    Executive,
-    InherentDataExt,
-    Nonce,
-    // ParachainSystem,
-    NumberFor,
-    Runtime,
-    RuntimeCall,
-    RuntimeGenesisConfig,
-    SessionKeys,
-    System,
-    TransactionPayment,
-    SLOT_DURATION,
-    VERSION,
+    AccountId, AssetConversion, Balance, Balances, Block, Executive, InherentDataExt,
+    NativeOrAssetId, Nonce, Runtime, RuntimeCall, RuntimeGenesisConfig, Session, SessionKeys,
+    System, TransactionPayment, SLOT_DURATION, VERSION,
 };
+#[cfg(feature = "parachain")]
+use super::{ConsensusHook, ParachainSystem};
+#[cfg(feature = "solochain")]
+use super::{Grandpa, NumberFor};
 
 impl_runtime_apis! {
     impl sp_consensus_aura::AuraApi<Block, AuraId> for Runtime {
@@ -75,17 +62,17 @@ impl_runtime_apis! {
         }
     }
 
-    // TODO: Configure runtime API
-    // impl cumulus_primitives_aura::AuraUnincludedSegmentApi<Block> for Runtime {
-    //     fn can_build_upon(
-    //         included_hash: <Block as BlockT>::Hash,
-    //         slot: cumulus_primitives_aura::Slot
-    //     ) -> bool {
-    //         ConsensusHook::can_build_upon(included_hash, slot)
-    //     }
-    // }
-
-    // TODO: Rmeove when migrating the node
+    #[cfg(feature = "parachain")]
+    impl cumulus_primitives_aura::AuraUnincludedSegmentApi<Block> for Runtime {
+        fn can_build_upon(
+            included_hash: <Block as BlockT>::Hash,
+            slot: cumulus_primitives_aura::Slot
+        ) -> bool {
+            ConsensusHook::can_build_upon(included_hash, slot)
+        }
+    }
+
+    #[cfg(feature = "solochain")]
     impl sp_consensus_grandpa::GrandpaApi<Block> for Runtime {
         fn grandpa_authorities() -> sp_consensus_grandpa::AuthorityList {
             Grandpa::grandpa_authorities()
@@ -243,18 +230,60 @@ impl_runtime_apis! {
         }
     }
 
-    // TODO: Configure runtime API
-    // impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
-    //     fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
-    //         ParachainSystem::collect_collation_info(header)
-    //     }
-    // }
+    // Quotes fees in an asset: the amount of `asset` that buys the native `partial_fee` reported by
+    // `TransactionPaymentApi::query_info` is
+    // `quote_price_tokens_for_exact_tokens(asset, NativeOrWithId::Native, partial_fee, true)`.
+    impl pallet_asset_conversion::AssetConversionApi<Block, Balance, NativeOrAssetId> for Runtime {
+        fn quote_price_exact_tokens_for_tokens(
+            asset1: NativeOrAssetId,
+            asset2: NativeOrAssetId,
+            amount: Balance,
+            include_fee: bool,
+        ) -> Option<Balance> {
+            AssetConversion::quote_price_exact_tokens_for_tokens(asset1, asset2, amount, include_fee)
+        }
+
+        fn quote_price_tokens_for_exact_tokens(
+            asset1: NativeOrAssetId,
+            asset2: NativeOrAssetId,
+            amount: Balance,
+            include_fee: bool,
+        ) -> Option<Balance> {
+            AssetConversion::quote_price_tokens_for_exact_tokens(asset1, asset2, amount, include_fee)
+        }
+
+        fn get_reserves(
+            asset1: NativeOrAssetId,
+            asset2: NativeOrAssetId,
+        ) -> Option<(Balance, Balance)> {
+            AssetConversion::get_reserves(asset1, asset2).ok()
+        }
+    }
+
+    impl chain_stats_rpc_runtime_api::ChainStatsApi<Block, Balance> for Runtime {
+        fn chain_stats() -> chain_stats_rpc_runtime_api::ChainStats<Balance> {
+            chain_stats_rpc_runtime_api::ChainStats {
+                total_issuance: Balances::total_issuance(),
+                accounts: frame_system::Account::<Runtime>::iter_keys().count() as u64,
+                collators: Authorities::<Runtime>::decode_len().unwrap_or_default() as u32,
+                session_index: Session::current_index(),
+            }
+        }
+    }
+
+    #[cfg(feature = "parachain")]
+    impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
+        fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
+            ParachainSystem::collect_collation_info(header)
+        }
+    }
 
     #[cfg(feature = "try-runtime")]
     impl frame_try_runtime::TryRuntime<Block> for Runtime {
         fn on_runtime_upgrade(checks: frame_try_runtime::UpgradeCheckSelect) -> (Weight, Weight) {
             use super::configs::RuntimeBlockWeights;
 
+            log::info!("try-runtime::on_runtime_upgrade parachain-from-scratch.");
             let weight = Executive::try_runtime_upgrade(checks).unwrap();
             (weight, RuntimeBlockWeights::get().max_block)
         }
@@ -283,6 +312,13 @@ impl_runtime_apis! {
             use cumulus_pallet_session_benchmarking::Pallet as SessionBench;
             use super::*;
 
+            #[cfg(feature = "parachain")]
+            use pallet_xcm::benchmarking::Pallet as PalletXcmExtrinsicsBenchmark;
+            #[cfg(feature = "parachain")]
+            type XcmBalances = pallet_xcm_benchmarks::fungible::Pallet::<Runtime>;
+            #[cfg(feature = "parachain")]
+            type XcmGeneric = pallet_xcm_benchmarks::generic::Pallet::<Runtime>;
+
             let mut list = Vec::<BenchmarkList>::new();
             list_benchmarks!(list, extra);
 
@@ -293,12 +329,15 @@ impl_runtime_apis! {
         fn dispatch_benchmark(
             config: frame_benchmarking::BenchmarkConfig
         ) -> Result<Vec<frame_benchmarking::BenchmarkBatch>, sp_runtime::RuntimeString> {
-            use frame_benchmarking::{BenchmarkError, Benchmarking, BenchmarkBatch};
+            use frame_benchmarking::{Benchmarking, BenchmarkBatch};
             use super::*;
 
             use frame_system_benchmarking::Pallet as SystemBench;
+            #[cfg(feature = "solochain")]
+            impl frame_system_benchmarking::Config for Runtime {}
+            #[cfg(feature = "parachain")]
             impl frame_system_benchmarking::Config for Runtime {
-                fn setup_set_code_requirements(code: &Vec<u8>) -> Result<(), BenchmarkError> {
+                fn setup_set_code_requirements(code: &Vec<u8>) -> Result<(), frame_benchmarking::BenchmarkError> {
                     ParachainSystem::initialize_for_set_code_benchmark(code.len() as u32);
                     Ok(())
                 }
@@ -311,6 +350,144 @@ impl_runtime_apis! {
             use cumulus_pallet_session_benchmarking::Pallet as SessionBench;
             impl cumulus_pallet_session_benchmarking::Config for Runtime {}
 
+            #[cfg(feature = "parachain")]
+            use crate::configs::xcm_config::{LocationToAccountId, RelayLocation, XcmConfig};
+            #[cfg(feature = "parachain")]
+            use frame_benchmarking::BenchmarkError;
+            #[cfg(feature = "parachain")]
+            use frame_support::parameter_types;
+            #[cfg(feature = "parachain")]
+            use xcm::latest::prelude::{
+                Asset, Assets as XcmAssets, InteriorLocation, Junction, Location, NetworkId, Response,
+            };
+
+            #[cfg(feature = "parachain")]
+            use pallet_xcm::benchmarking::Pallet as PalletXcmExtrinsicsBenchmark;
+            #[cfg(feature = "parachain")]
+            type XcmBalances = pallet_xcm_benchmarks::fungible::Pallet::<Runtime>;
+            #[cfg(feature = "parachain")]
+            type XcmGeneric = pallet_xcm_benchmarks::generic::Pallet::<Runtime>;
+
+            // Delivery of messages to the relay chain is free, see `XcmRouter`.
+            #[cfg(feature = "parachain")]
+            type DeliveryHelper = cumulus_primitives_utility::ToParentDeliveryHelper<
+                XcmConfig,
+                ExistentialDepositAsset,
+                (),
+            >;
+
+            #[cfg(feature = "parachain")]
+            parameter_types! {
+                pub ExistentialDepositAsset: Option<Asset> =
+                    Some((RelayLocation::get(), EXISTENTIAL_DEPOSIT).into());
+                pub TrustedReserve: Option<(Location, Asset)> =
+                    Some((RelayLocation::get(), (RelayLocation::get(), UNIT).into()));
+                pub const CheckedAccount: Option<(AccountId, xcm_builder::MintLocation)> = None;
+                pub const TrustedTeleporter: Option<(Location, Asset)> = None;
+            }
+
+            #[cfg(feature = "parachain")]
+            impl pallet_xcm::benchmarking::Config for Runtime {
+                type DeliveryHelper = DeliveryHelper;
+
+                fn reachable_dest() -> Option<Location> {
+                    Some(RelayLocation::get())
+                }
+
+                fn teleportable_asset_and_dest() -> Option<(Asset, Location)> {
+                    // Teleports are disabled.
+                    None
+                }
+
+                fn reserve_transferable_asset_and_dest() -> Option<(Asset, Location)> {
+                    // Reserve transfers are only allowed towards the chains trusted by
+                    // `XcmTrust`, of which there are none in the benchmark genesis.
+                    None
+                }
+
+                fn get_asset() -> Asset {
+                    (RelayLocation::get(), EXISTENTIAL_DEPOSIT).into()
+                }
+            }
+
+            #[cfg(feature = "parachain")]
+            impl pallet_xcm_benchmarks::Config for Runtime {
+                type XcmConfig = XcmConfig;
+                type AccountIdConverter = LocationToAccountId;
+                type DeliveryHelper = DeliveryHelper;
+
+                fn valid_destination() -> Result<Location, BenchmarkError> {
+                    Ok(RelayLocation::get())
+                }
+
+                fn worst_case_holding(_depositable_count: u32) -> XcmAssets {
+                    // The native token is the only asset transacted by `Balances`.
+                    sp_std::vec![(RelayLocation::get(), 1_000_000 * UNIT).into()].into()
+                }
+            }
+
+            #[cfg(feature = "parachain")]
+            impl pallet_xcm_benchmarks::fungible::Config for Runtime {
+                type TransactAsset = Balances;
+                type CheckedAccount = CheckedAccount;
+                type TrustedTeleporter = TrustedTeleporter;
+                type TrustedReserve = TrustedReserve;
+
+                fn get_asset() -> Asset {
+                    (RelayLocation::get(), UNIT).into()
+                }
+            }
+
+            #[cfg(feature = "parachain")]
+            impl pallet_xcm_benchmarks::generic::Config for Runtime {
+                type TransactAsset = Balances;
+                type RuntimeCall = RuntimeCall;
+
+                fn worst_case_response() -> (u64, Response) {
+                    (0u64, Response::Version(Default::default()))
+                }
+
+                fn worst_case_asset_exchange() -> Result<(XcmAssets, XcmAssets), BenchmarkError> {
+                    Err(BenchmarkError::Skip)
+                }
+
+                fn universal_alias() -> Result<(Location, Junction), BenchmarkError> {
+                    Err(BenchmarkError::Skip)
+                }
+
+                fn transact_origin_and_runtime_call() -> Result<(Location, RuntimeCall), BenchmarkError> {
+                    Ok((RelayLocation::get(), frame_system::Call::remark_with_event { remark: Vec::new() }.into()))
+                }
+
+                fn subscribe_origin() -> Result<Location, BenchmarkError> {
+                    Ok(RelayLocation::get())
+                }
+
+                fn claimable_asset() -> Result<(Location, Location, XcmAssets), BenchmarkError> {
+                    let origin = RelayLocation::get();
+                    let assets: XcmAssets = (RelayLocation::get(), 1_000 * UNIT).into();
+                    let ticket = Location::here();
+                    Ok((origin, ticket, assets))
+                }
+
+                fn fee_asset() -> Result<Asset, BenchmarkError> {
+                    Ok((RelayLocation::get(), 1_000_000 * UNIT).into())
+                }
+
+                fn unlockable_asset() -> Result<(Location, Location, Asset), BenchmarkError> {
+                    Err(BenchmarkError::Skip)
+                }
+
+                fn export_message_origin_and_destination(
+                ) -> Result<(Location, NetworkId, InteriorLocation), BenchmarkError> {
+                    Err(BenchmarkError::Skip)
+                }
+
+                fn alias_origin() -> Result<(Location, Location), BenchmarkError> {
+                    Err(BenchmarkError::Skip)
+                }
+            }
+
             use frame_support::traits::WhitelistedStorageKeys;
             let whitelist = AllPalletsWithSystem::whitelisted_storage_keys();
 
@@ -23,16 +23,55 @@
 //
 // For more information, please refer to <http://unlicense.org>
 
-// TODO: Define benchmarks
+#[cfg(feature = "solochain")]
 frame_benchmarking::define_benchmarks!(
     // Only benchmark the following pallets
     [frame_system, SystemBench::<Runtime>]
-    // [cumulus_pallet_parachain_system, ParachainSystem]
     [pallet_timestamp, Timestamp]
     [pallet_balances, Balances]
+    [pallet_treasury, Treasury]
+    [pallet_assets, Assets]
+    [pallet_assets, PoolAssets]
+    [pallet_asset_conversion, AssetConversion]
     [pallet_sudo, Sudo]
-    // [pallet_collator_selection, CollatorSelection]
     [pallet_session, SessionBench::<Runtime>]
-    // [cumulus_pallet_xcmp_queue, XcmpQueue]
+    [pallet_utility, Utility]
+    [pallet_identity, Identity]
+    [pallet_team_registry, TeamRegistry]
+    [pallet_scheduler, Scheduler]
+    [pallet_preimage, Preimage]
+    [pallet_collective, Council]
+    [pallet_conviction_voting, ConvictionVoting]
+    [pallet_referenda, Referenda]
+);
+
+#[cfg(feature = "parachain")]
+frame_benchmarking::define_benchmarks!(
+    // Only benchmark the following pallets
+    [frame_system, SystemBench::<Runtime>]
+    [cumulus_pallet_parachain_system, ParachainSystem]
+    [pallet_timestamp, Timestamp]
+    [pallet_balances, Balances]
+    [pallet_treasury, Treasury]
+    [pallet_assets, Assets]
+    [pallet_assets, ForeignAssets]
+    [pallet_assets, PoolAssets]
+    [pallet_asset_conversion, AssetConversion]
+    [pallet_sudo, Sudo]
+    [pallet_collator_selection, CollatorSelection]
+    [pallet_session, SessionBench::<Runtime>]
+    [cumulus_pallet_xcmp_queue, XcmpQueue]
     [pallet_message_queue, MessageQueue]
+    [pallet_xcm, PalletXcmExtrinsicsBenchmark::<Runtime>]
+    [pallet_xcm_benchmarks::fungible, XcmBalances]
+    [pallet_xcm_benchmarks::generic, XcmGeneric]
+    [pallet_xcm_trust, XcmTrust]
+    [pallet_utility, Utility]
+    [pallet_identity, Identity]
+    [pallet_team_registry, TeamRegistry]
+    [pallet_scheduler, Scheduler]
+    [pallet_preimage, Preimage]
+    [pallet_collective, Council]
+    [pallet_conviction_voting, ConvictionVoting]
+    [pallet_referenda, Referenda]
 );
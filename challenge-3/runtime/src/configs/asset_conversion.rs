@@ -0,0 +1,180 @@
+// This is free and unencumbered software released into the public domain.
+//
+// Anyone is free to copy, modify, publish, use, compile, sell, or
+// distribute this software, either in source code form or as a compiled
+// binary, for any purpose, commercial or non-commercial, and by any
+// means.
+//
+// In jurisdictions that recognize copyright laws, the author or authors
+// of this software dedicate any and all copyright interest in the
+// software to the public domain. We make this dedication for the benefit
+// of the public at large and to the detriment of our heirs and
+// successors. We intend this dedication to be an overt act of
+// relinquishment in perpetuity of all present and future rights to this
+// software under copyright law.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.
+// IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY CLAIM, DAMAGES OR
+// OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE,
+// ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+//
+// For more information, please refer to <http://unlicense.org>
+
+//! Swaps between the native token and assets, used to let users pay transaction fees in an asset
+//! that has a pool with the native token.
+//!
+//! On the parachain the swappable assets are the foreign assets, such as stablecoins reserve
+//! transferred from Asset Hub. The solochain has no foreign assets and uses its local assets
+//! instead.
+
+use frame_support::{
+    ord_parameter_types, parameter_types,
+    traits::{
+        fungible::{NativeFromLeft, NativeOrWithId, UnionOf},
+        tokens::imbalance::ResolveAssetTo,
+        AsEnsureOriginWithArg, ConstU128, ConstU32,
+    },
+    PalletId,
+};
+use frame_system::EnsureSignedBy;
+use pallet_asset_conversion::{AccountIdConverter, Ascending, Chain, WithFirstAsset};
+use pallet_asset_conversion_tx_payment::AssetConversionAdapter;
+use sp_runtime::{traits::AccountIdConversion, Permill};
+
+#[cfg(feature = "parachain")]
+use super::ForeignAssetsInstance;
+#[cfg(feature = "solochain")]
+use super::TrustBackedAssetsInstance;
+use super::{ApprovalDeposit, AssetsStringLimit, EnsureRootOrHalfCouncil, TreasuryAccount};
+#[cfg(feature = "solochain")]
+use crate::Assets;
+#[cfg(feature = "parachain")]
+use crate::ForeignAssets;
+use crate::{
+    AccountId, AssetConversion, Balance, Balances, PoolAssets, Runtime, RuntimeEvent, UNIT,
+};
+
+/// The assets that can be swapped for the native token.
+#[cfg(feature = "solochain")]
+type SwappableAssets = Assets;
+#[cfg(feature = "solochain")]
+type SwappableAssetsInstance = TrustBackedAssetsInstance;
+#[cfg(feature = "parachain")]
+type SwappableAssets = ForeignAssets;
+#[cfg(feature = "parachain")]
+type SwappableAssetsInstance = ForeignAssetsInstance;
+
+/// Liquidity pool tokens, minted by `pallet_asset_conversion` to liquidity providers.
+pub type PoolAssetsInstance = pallet_assets::Instance3;
+
+/// The native token or a swappable asset.
+pub type NativeOrAssetId =
+    NativeOrWithId<<Runtime as pallet_assets::Config<SwappableAssetsInstance>>::AssetId>;
+
+/// The native token and the swappable assets, as a single `fungibles` implementation.
+pub type NativeAndAssets =
+    UnionOf<Balances, SwappableAssets, NativeFromLeft, NativeOrAssetId, AccountId>;
+
+parameter_types! {
+    pub const AssetConversionPalletId: PalletId = PalletId(*b"py/ascon");
+    pub const Native: NativeOrAssetId = NativeOrWithId::Native;
+    pub const PoolSetupFee: Balance = 10 * UNIT;
+    pub const MintMinLiquidity: Balance = 100;
+    pub const LiquidityWithdrawalFee: Permill = Permill::from_percent(0);
+}
+
+/// Derives the account of a pool from its id.
+pub type PoolIdToAccountId =
+    AccountIdConverter<AssetConversionPalletId, (NativeOrAssetId, NativeOrAssetId)>;
+
+ord_parameter_types! {
+    pub const AssetConversionOrigin: AccountId =
+        AccountIdConversion::<AccountId>::into_account_truncating(&AssetConversionPalletId::get());
+}
+
+impl pallet_assets::Config<PoolAssetsInstance> for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type RemoveItemsLimit = ConstU32<1000>;
+    type AssetId = u32;
+    type AssetIdParameter = u32;
+    type Currency = Balances;
+    // Pool tokens are only created by `pallet_asset_conversion`, along with their pool.
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureSignedBy<AssetConversionOrigin, AccountId>>;
+    type ForceOrigin = EnsureRootOrHalfCouncil;
+    // The pool setup fee already covers the storage of a pool and its token.
+    type AssetDeposit = ConstU128<0>;
+    type AssetAccountDeposit = ConstU128<0>;
+    type MetadataDepositBase = ConstU128<0>;
+    type MetadataDepositPerByte = ConstU128<0>;
+    type ApprovalDeposit = ApprovalDeposit;
+    type StringLimit = AssetsStringLimit;
+    type Freezer = ();
+    type Extra = ();
+    type CallbackHandle = ();
+    type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+}
+
+impl pallet_asset_conversion::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type HigherPrecisionBalance = primitive_types::U256;
+    type AssetKind = NativeOrAssetId;
+    type Assets = NativeAndAssets;
+    type PoolId = (Self::AssetKind, Self::AssetKind);
+    // Pools with the native token have it first, any other pair is in ascending order.
+    type PoolLocator = Chain<
+        WithFirstAsset<Native, AccountId, NativeOrAssetId, PoolIdToAccountId>,
+        Ascending<AccountId, NativeOrAssetId, PoolIdToAccountId>,
+    >;
+    type PoolAssetId = u32;
+    type PoolAssets = PoolAssets;
+    type PoolSetupFee = PoolSetupFee;
+    type PoolSetupFeeAsset = Native;
+    type PoolSetupFeeTarget = ResolveAssetTo<TreasuryAccount, Self::Assets>;
+    type LiquidityWithdrawalFee = LiquidityWithdrawalFee;
+    // 0.3%
+    type LPFee = ConstU32<3>;
+    type PalletId = AssetConversionPalletId;
+    type MaxSwapPathLength = ConstU32<3>;
+    type MintMinLiquidity = MintMinLiquidity;
+    type WeightInfo = pallet_asset_conversion::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = AssetConversionBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct AssetConversionBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_asset_conversion::BenchmarkHelper<NativeOrAssetId> for AssetConversionBenchmarkHelper {
+    fn create_pair(seed1: u32, seed2: u32) -> (NativeOrAssetId, NativeOrAssetId) {
+        #[cfg(feature = "solochain")]
+        let asset = |seed: u32| NativeOrWithId::WithId(seed);
+        #[cfg(feature = "parachain")]
+        let asset = |seed: u32| {
+            NativeOrWithId::WithId(xcm::latest::Location::new(
+                1,
+                [xcm::latest::Junction::Parachain(seed)],
+            ))
+        };
+
+        match seed1 {
+            0 => (NativeOrWithId::Native, asset(seed2)),
+            _ => (asset(seed1), asset(seed2)),
+        }
+    }
+}
+
+impl pallet_asset_conversion_tx_payment::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Fungibles = SwappableAssets;
+    // Fees paid in an asset are swapped to the native token in the pool of the asset, then charged
+    // by `pallet_transaction_payment` like any other fee.
+    type OnChargeAssetTransaction = AssetConversionAdapter<Balances, AssetConversion, Native>;
+}
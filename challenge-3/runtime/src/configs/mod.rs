@@ -23,94 +23,60 @@
 //
 // For more information, please refer to <http://unlicense.org>
 
-// #[path = "xcm.rs"]
-// mod xcm_config;
+mod asset_conversion;
+mod governance;
+#[cfg(feature = "parachain")]
+mod parachain;
+#[cfg(feature = "solochain")]
+mod solochain;
+#[cfg(feature = "parachain")]
+#[path = "xcm.rs"]
+pub mod xcm_config;
+
+pub use asset_conversion::NativeOrAssetId;
+pub use governance::{CouncilCollective, EnsureRootOrHalfCouncil};
+#[cfg(feature = "parachain")]
+pub use parachain::{ForeignAssetsInstance, PotId};
 
 // Substrate and Polkadot dependencies
-// TODO: Import crates
-// use cumulus_pallet_parachain_system::RelayNumberMonotonicallyIncreases;
-// use cumulus_primitives_core::{AggregateMessageOrigin, ParaId};
 use frame_support::{
     derive_impl,
-    // dispatch::DispatchClass,
+    dispatch::DispatchClass,
     parameter_types,
     traits::{
-        ConstBool,
-        ConstU32,
-        ConstU64,
-        ConstU8,
-        // EitherOfDiverse,
-        // TransformOrigin,
-        VariantCountOf,
+        tokens::{PayFromAccount, UnityAssetBalanceConversion},
+        AsEnsureOriginWithArg, ConstBool, ConstU32, ConstU64, ConstU8, EitherOf, VariantCountOf,
     },
-    weights::{ConstantMultiplier, Weight},
+    weights::ConstantMultiplier,
     PalletId,
 };
 use frame_system::{
     limits::{BlockLength, BlockWeights},
-    // EnsureRoot,
-};
-// TODO: Import crates
-// use pallet_xcm::{EnsureXcm, IsVoiceOfBody};
-// use parachains_common::message_queue::{NarrowOriginToSibling, ParaIdToSibling};
-// use polkadot_runtime_common::{
-//     xcm_sender::NoPriceForMessageDelivery, BlockHashCount, SlowAdjustingFeeUpdate,
-// };
-use pallet_transaction_payment::{
-    ConstFeeMultiplier,
-    //  FungibleAdapter,
-    Multiplier,
+    EnsureRoot, EnsureSigned, EnsureWithSuccess,
 };
+use pallet_transaction_payment::{Multiplier, TargetedFeeAdjustment};
+#[cfg(feature = "parachain")]
+use parachains_common::TREASURY_PALLET_ID;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
-use sp_runtime::{traits::One, Perbill};
+use sp_runtime::{
+    traits::{AccountIdConversion, Bounded, IdentityLookup, Verify},
+    FixedPointNumber, Percent, Perquintill,
+};
 use sp_version::RuntimeVersion;
-// TODO: Import crates
-// use xcm::latest::prelude::BodyId;
 
-// TODO: Import crates
 // Local module imports
+#[cfg(feature = "parachain")]
+use super::CollatorSelection;
 use super::{
-    weights::{
-        BlockExecutionWeight,
-        // ExtrinsicBaseWeight,
-        RocksDbWeight,
-    },
-    AccountId,
-    Aura,
-    Balance,
-    Balances,
-    Block,
-    BlockNumber,
-    // CollatorSelection,
-    // ConsensusHook,
-    Hash,
-    // MessageQueue,
-    Nonce,
-    PalletInfo,
-    // ParachainSystem,
-    Runtime,
-    RuntimeCall,
-    RuntimeEvent,
-    RuntimeFreezeReason,
-    RuntimeHoldReason,
-    RuntimeOrigin,
-    RuntimeTask,
-    // Session,
-    SessionKeys,
-    System,
-    WeightToFee,
-    // XcmpQueue,
-    // AVERAGE_ON_INITIALIZE_RATIO,
-    EXISTENTIAL_DEPOSIT,
-    HOURS,
-    // MAXIMUM_BLOCK_WEIGHT,
-    MICROUNIT,
-    NORMAL_DISPATCH_RATIO,
-    SLOT_DURATION,
-    VERSION,
+    deposit,
+    impls::DealWithFees,
+    weights::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight},
+    AccountId, Aura, Balance, Balances, Block, BlockNumber, Hash, Nonce, OriginCaller, PalletInfo,
+    Runtime, RuntimeCall, RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin,
+    RuntimeTask, SessionKeys, Signature, System, WeightToFee, AVERAGE_ON_INITIALIZE_RATIO,
+    CENTIUNIT, DAYS, EXISTENTIAL_DEPOSIT, HOURS, MAXIMUM_BLOCK_WEIGHT, MICROUNIT, MILLIUNIT,
+    NORMAL_DISPATCH_RATIO, SLOT_DURATION, UNIT, VERSION,
 };
-// TODO: Import crates
-// use xcm_config::{RelayLocation, XcmOriginToTransactDispatchOrigin};
 
 parameter_types! {
     pub const Version: RuntimeVersion = VERSION;
@@ -123,80 +89,69 @@ parameter_types! {
         BlockLength::max_with_normal_ratio(5 * 1024 * 1024, NORMAL_DISPATCH_RATIO);
     pub RuntimeBlockWeights: BlockWeights = BlockWeights::builder()
         .base_block(BlockExecutionWeight::get())
-        // .for_class(DispatchClass::all(), |weights| {
-        //     weights.base_extrinsic = ExtrinsicBaseWeight::get();
-        // })
-        // .for_class(DispatchClass::Normal, |weights| {
-        //     weights.max_total = Some(NORMAL_DISPATCH_RATIO * MAXIMUM_BLOCK_WEIGHT);
-        // })
-        // .for_class(DispatchClass::Operational, |weights| {
-        //     weights.max_total = Some(MAXIMUM_BLOCK_WEIGHT);
-        //     // Operational transactions have some extra reserved space, so that they
-        //     // are included even if block reached `MAXIMUM_BLOCK_WEIGHT`.
-        //     weights.reserved = Some(
-        //         MAXIMUM_BLOCK_WEIGHT - NORMAL_DISPATCH_RATIO * MAXIMUM_BLOCK_WEIGHT
-        //     );
-        // })
-        // .avg_block_initialization(AVERAGE_ON_INITIALIZE_RATIO)
+        .for_class(DispatchClass::all(), |weights| {
+            weights.base_extrinsic = ExtrinsicBaseWeight::get();
+        })
+        .for_class(DispatchClass::Normal, |weights| {
+            weights.max_total = Some(NORMAL_DISPATCH_RATIO * MAXIMUM_BLOCK_WEIGHT);
+        })
+        .for_class(DispatchClass::Operational, |weights| {
+            weights.max_total = Some(MAXIMUM_BLOCK_WEIGHT);
+            // Operational transactions have some extra reserved space, so that they
+            // are included even if block reached `MAXIMUM_BLOCK_WEIGHT`.
+            weights.reserved = Some(
+                MAXIMUM_BLOCK_WEIGHT - NORMAL_DISPATCH_RATIO * MAXIMUM_BLOCK_WEIGHT
+            );
+        })
+        .avg_block_initialization(AVERAGE_ON_INITIALIZE_RATIO)
         .build_or_panic();
+    pub const BlockHashCount: BlockNumber = 4096;
     pub const SS58Prefix: u16 = 42;
 }
 
-// TODO: Update the solochain config
 /// The default types are being injected by [`derive_impl`](`frame_support::derive_impl`) from
-/// [`ParaChainDefaultConfig`](`struct@frame_system::config_preludes::ParaChainDefaultConfig`),
-/// but overridden as needed.
-#[derive_impl(frame_system::config_preludes::SolochainDefaultConfig)]
+/// [`SolochainDefaultConfig`](`struct@frame_system::config_preludes::SolochainDefaultConfig`)
+/// or [`ParaChainDefaultConfig`](`struct@frame_system::config_preludes::ParaChainDefaultConfig`),
+/// depending on the runtime flavour, but overridden as needed.
+#[cfg_attr(
+    feature = "solochain",
+    derive_impl(frame_system::config_preludes::SolochainDefaultConfig)
+)]
+#[cfg_attr(
+    feature = "parachain",
+    derive_impl(frame_system::config_preludes::ParaChainDefaultConfig)
+)]
 impl frame_system::Config for Runtime {
-    // TODO: configure pallet
-    // /// The identifier used to distinguish between accounts.
-    // type AccountId = AccountId;
-    // /// The index type for storing how many extrinsics an account has signed.
-    // type Nonce = Nonce;
-    // /// The type for hashing blocks and tries.
-    // type Hash = Hash;
-    // /// The block type.
-    // type Block = Block;
-    // /// Maximum number of block number to block hash mappings to keep (oldest pruned first).
-    // type BlockHashCount = BlockHashCount;
-    // /// Runtime version.
-    // type Version = Version;
-    // /// The data to be stored in an account.
-    // type AccountData = pallet_balances::AccountData<Balance>;
-    // /// The weight of database operations that the runtime can invoke.
-    // type DbWeight = RocksDbWeight;
-    // /// Block & extrinsics weights: base values and limits.
-    // type BlockWeights = RuntimeBlockWeights;
-    // /// The maximum length of a block (in bytes).
-    // type BlockLength = RuntimeBlockLength;
-    // /// This is used as an identifier of the chain. 42 is the generic substrate prefix.
-    // type SS58Prefix = SS58Prefix;
-    // /// The action to take on a Runtime Upgrade
-    // type OnSetCode = cumulus_pallet_parachain_system::ParachainSetCode<Self>;
-    // type MaxConsumers = frame_support::traits::ConstU32<16>;
-
-    /// The block type for the runtime.
-    type Block = Block;
-    /// Block & extrinsics weights: base values and limits.
-    type BlockWeights = RuntimeBlockWeights;
-    /// The maximum length of a block (in bytes).
-    type BlockLength = RuntimeBlockLength;
     /// The identifier used to distinguish between accounts.
     type AccountId = AccountId;
-    /// The type for storing how many extrinsics an account has signed.
+    /// The index type for storing how many extrinsics an account has signed.
     type Nonce = Nonce;
     /// The type for hashing blocks and tries.
     type Hash = Hash;
+    /// The block type.
+    type Block = Block;
     /// Maximum number of block number to block hash mappings to keep (oldest pruned first).
-    type BlockHashCount = ConstU32<250>;
-    /// The weight of database operations that the runtime can invoke.
-    type DbWeight = RocksDbWeight;
-    /// Version of the runtime.
+    type BlockHashCount = BlockHashCount;
+    /// Runtime version.
     type Version = Version;
     /// The data to be stored in an account.
     type AccountData = pallet_balances::AccountData<Balance>;
+    /// The weight of database operations that the runtime can invoke.
+    type DbWeight = RocksDbWeight;
+    /// Weight information for the extrinsics of this pallet.
+    type SystemWeightInfo = crate::weights::frame_system::WeightInfo<Runtime>;
+    /// Block & extrinsics weights: base values and limits.
+    type BlockWeights = RuntimeBlockWeights;
+    /// The maximum length of a block (in bytes).
+    type BlockLength = RuntimeBlockLength;
     /// This is used as an identifier of the chain. 42 is the generic substrate prefix.
     type SS58Prefix = SS58Prefix;
+    /// The action to take on a Runtime Upgrade
+    #[cfg(feature = "solochain")]
+    type OnSetCode = ();
+    /// The action to take on a Runtime Upgrade
+    #[cfg(feature = "parachain")]
+    type OnSetCode = cumulus_pallet_parachain_system::ParachainSetCode<Self>;
     type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
@@ -205,13 +160,15 @@ impl pallet_timestamp::Config for Runtime {
     type Moment = u64;
     type OnTimestampSet = Aura;
     type MinimumPeriod = ConstU64<0>;
-    type WeightInfo = (); // Configure based on benchmarking results.
+    type WeightInfo = crate::weights::pallet_timestamp::WeightInfo<Runtime>;
 }
 
 impl pallet_authorship::Config for Runtime {
     type FindAuthor = pallet_session::FindAccountFromAuthorIndex<Self, Aura>;
-    // type EventHandler = (CollatorSelection,);
+    #[cfg(feature = "solochain")]
     type EventHandler = ();
+    #[cfg(feature = "parachain")]
+    type EventHandler = (CollatorSelection,);
 }
 
 parameter_types! {
@@ -227,7 +184,7 @@ impl pallet_balances::Config for Runtime {
     type DustRemoval = ();
     type ExistentialDeposit = ExistentialDeposit;
     type AccountStore = System;
-    type WeightInfo = (); // Configure based on benchmarking results.
+    type WeightInfo = crate::weights::pallet_balances::WeightInfo<Runtime>;
     type MaxReserves = ConstU32<50>;
     type ReserveIdentifier = [u8; 8];
     type RuntimeHoldReason = RuntimeHoldReason;
@@ -239,116 +196,144 @@ impl pallet_balances::Config for Runtime {
 parameter_types! {
     /// Relay Chain `TransactionByteFee` / 10
     pub const TransactionByteFee: Balance = 10 * MICROUNIT;
+    /// Share of the transaction fees and tips that goes to the treasury, the rest goes to the
+    /// collators.
+    pub const TreasuryFeeShare: Percent = Percent::from_percent(80);
 }
 
-// TODO: Update config
 parameter_types! {
-    pub FeeMultiplier: Multiplier = Multiplier::one();
+    /// The portion of the `NORMAL_DISPATCH_RATIO` that we adjust the fees with. Blocks filled less
+    /// than this will decrease the weight and more will increase.
+    pub const TargetBlockFullness: Perquintill = Perquintill::from_percent(25);
+    /// The adjustment variable of the runtime. Higher values will cause `TargetBlockFullness` to
+    /// change the fees more rapidly.
+    pub AdjustmentVariable: Multiplier = Multiplier::saturating_from_rational(75, 1_000_000);
+    /// Minimum amount of the multiplier. This value cannot be too low. A test case should ensure
+    /// that combined with `AdjustmentVariable`, we can recover from the minimum.
+    pub MinimumMultiplier: Multiplier = Multiplier::saturating_from_rational(1, 10u128);
+    /// The maximum amount of the multiplier.
+    pub MaximumMultiplier: Multiplier = Bounded::max_value();
 }
 
-// TODO: Update config
+/// Parameterized slow adjusting fee updated based on the fullness of the `Normal` dispatch class,
+/// as in `polkadot_runtime_common::SlowAdjustingFeeUpdate`. It is defined here so the solochain,
+/// which does not depend on `polkadot-runtime-common`, uses the same fee curve.
+pub type SlowAdjustingFeeUpdate<R> = TargetedFeeAdjustment<
+    R,
+    TargetBlockFullness,
+    AdjustmentVariable,
+    MinimumMultiplier,
+    MaximumMultiplier,
+>;
+
 impl pallet_transaction_payment::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    type OnChargeTransaction = pallet_transaction_payment::FungibleAdapter<Balances, ()>;
+    type OnChargeTransaction =
+        pallet_transaction_payment::FungibleAdapter<Balances, DealWithFees<TreasuryFeeShare>>;
     type WeightToFee = WeightToFee;
     type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
-    // type FeeMultiplierUpdate = SlowAdjustingFeeUpdate<Self>;
-    type FeeMultiplierUpdate = ConstFeeMultiplier<FeeMultiplier>;
+    type FeeMultiplierUpdate = SlowAdjustingFeeUpdate<Self>;
     type OperationalFeeMultiplier = ConstU8<5>;
 }
 
-impl pallet_sudo::Config for Runtime {
-    type RuntimeEvent = RuntimeEvent;
-    type RuntimeCall = RuntimeCall;
-    type WeightInfo = (); // Configure based on benchmarking results.
+/// The pallet id of the treasury on the solochain.
+///
+/// Parachains use `parachains_common::TREASURY_PALLET_ID`, which has the same value, so the
+/// treasury account is kept when the solochain state is migrated.
+#[cfg(feature = "solochain")]
+const TREASURY_PALLET_ID: PalletId = PalletId(*b"py/trsry");
+
+parameter_types! {
+    pub const TreasuryPalletId: PalletId = TREASURY_PALLET_ID;
+    pub TreasuryAccount: AccountId = TreasuryPalletId::get().into_account_truncating();
+    pub const SpendPeriod: BlockNumber = 6 * DAYS;
+    pub const PayoutPeriod: BlockNumber = 30 * DAYS;
+    pub const MaxBalance: Balance = Balance::MAX;
+    /// The most the council can spend without going through a referendum.
+    pub const CouncilSpendLimit: Balance = 1_000 * UNIT;
 }
 
-// TODO: Remove when switching to parachain.
-impl pallet_grandpa::Config for Runtime {
+impl pallet_treasury::Config for Runtime {
+    type PalletId = TreasuryPalletId;
+    type Currency = Balances;
+    type RejectOrigin = EnsureRootOrHalfCouncil;
     type RuntimeEvent = RuntimeEvent;
+    type SpendPeriod = SpendPeriod;
+    type Burn = ();
+    type BurnDestination = ();
+    type SpendFunds = ();
+    type WeightInfo = pallet_treasury::weights::SubstrateWeight<Runtime>;
+    type MaxApprovals = ConstU32<100>;
+    type SpendOrigin = EitherOf<
+        EnsureWithSuccess<EnsureRoot<AccountId>, AccountId, MaxBalance>,
+        EnsureWithSuccess<
+            pallet_collective::EnsureProportionMoreThan<AccountId, CouncilCollective, 1, 2>,
+            AccountId,
+            CouncilSpendLimit,
+        >,
+    >;
+    type AssetKind = ();
+    type Beneficiary = AccountId;
+    type BeneficiaryLookup = IdentityLookup<AccountId>;
+    type Paymaster = PayFromAccount<Balances, TreasuryAccount>;
+    type BalanceConverter = UnityAssetBalanceConversion;
+    type PayoutPeriod = PayoutPeriod;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = TreasuryBenchmarkHelper;
+}
 
-    type WeightInfo = ();
-    type MaxAuthorities = ConstU32<32>;
-    type MaxNominators = ConstU32<0>;
-    type MaxSetIdSessionEntries = ConstU64<0>;
+#[cfg(feature = "runtime-benchmarks")]
+pub struct TreasuryBenchmarkHelper;
 
-    type KeyOwnerProof = sp_core::Void;
-    type EquivocationReportSystem = ();
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_treasury::ArgumentsFactory<(), AccountId> for TreasuryBenchmarkHelper {
+    fn create_asset_kind(_seed: u32) {}
+
+    fn create_beneficiary(seed: [u8; 32]) -> AccountId {
+        AccountId::from(seed)
+    }
 }
 
-// TODO: Configure variables
-// parameter_types! {
-//     pub const ReservedXcmpWeight: Weight = MAXIMUM_BLOCK_WEIGHT.saturating_div(4);
-//     pub const ReservedDmpWeight: Weight = MAXIMUM_BLOCK_WEIGHT.saturating_div(4);
-//     pub const RelayOrigin: AggregateMessageOrigin = AggregateMessageOrigin::Parent;
-// }
-
-// TODO: Configure pallet
-// impl cumulus_pallet_parachain_system::Config for Runtime {
-//     type WeightInfo = (); // Configure based on benchmarking results.
-//     type RuntimeEvent = RuntimeEvent;
-//     type OnSystemEvent = ();
-//     type SelfParaId = parachain_info::Pallet<Runtime>;
-//     type OutboundXcmpMessageSource = XcmpQueue;
-//     type DmpQueue = frame_support::traits::EnqueueWithOrigin<MessageQueue, RelayOrigin>;
-//     type ReservedDmpWeight = ReservedDmpWeight;
-//     type XcmpMessageHandler = XcmpQueue;
-//     type ReservedXcmpWeight = ReservedXcmpWeight;
-//     type CheckAssociatedRelayNumber = RelayNumberMonotonicallyIncreases;
-//     type ConsensusHook = ConsensusHook;
-// }
-
-// TODO: Configure pallet
-// impl parachain_info::Config for Runtime {}
+/// Assets created by users of the chain.
+pub type TrustBackedAssetsInstance = pallet_assets::Instance1;
 
 parameter_types! {
-    pub MessageQueueServiceWeight: Weight = Perbill::from_percent(35) * RuntimeBlockWeights::get().max_block;
+    pub const AssetDeposit: Balance = 10 * UNIT;
+    pub const AssetAccountDeposit: Balance = deposit(1, 16);
+    pub const AssetsStringLimit: u32 = 50;
+    pub const MetadataDepositBase: Balance = deposit(1, 68);
+    pub const MetadataDepositPerByte: Balance = deposit(0, 1);
+    pub const ApprovalDeposit: Balance = EXISTENTIAL_DEPOSIT;
 }
 
-// TODO: Configure pallet
-// impl pallet_message_queue::Config for Runtime {
-//     type RuntimeEvent = RuntimeEvent;
-//     type WeightInfo = (); // Configure based on benchmarking results.
-//     #[cfg(feature = "runtime-benchmarks")]
-//     type MessageProcessor = pallet_message_queue::mock_helpers::NoopMessageProcessor<
-//         cumulus_primitives_core::AggregateMessageOrigin,
-//     >;
-//     #[cfg(not(feature = "runtime-benchmarks"))]
-//     type MessageProcessor = xcm_builder::ProcessXcmMessage<
-//         AggregateMessageOrigin,
-//         xcm_executor::XcmExecutor<xcm_config::XcmConfig>,
-//         RuntimeCall,
-//     >;
-//     type Size = u32;
-//     // The XCMP queue pallet is only ever able to handle the `Sibling(ParaId)` origin:
-//     type QueueChangeHandler = NarrowOriginToSibling<XcmpQueue>;
-//     type QueuePausedQuery = NarrowOriginToSibling<XcmpQueue>;
-//     type HeapSize = sp_core::ConstU32<{ 103 * 1024 }>;
-//     type MaxStale = sp_core::ConstU32<8>;
-//     type ServiceWeight = MessageQueueServiceWeight;
-//     type IdleMaxServiceWeight = ();
-// }
-
-// TODO: Configure pallet
-// impl cumulus_pallet_aura_ext::Config for Runtime {}
-
-// TODO: Configure pallet
-// impl cumulus_pallet_xcmp_queue::Config for Runtime {
-//     type RuntimeEvent = RuntimeEvent;
-//     type ChannelInfo = ParachainSystem;
-//     type VersionWrapper = ();
-//     // Enqueue XCMP messages from siblings for later processing.
-//     type XcmpQueue = TransformOrigin<MessageQueue, AggregateMessageOrigin, ParaId, ParaIdToSibling>;
-//     type MaxInboundSuspended = sp_core::ConstU32<1_000>;
-//     type ControllerOrigin = EnsureRoot<AccountId>;
-//     type ControllerOriginConverter = XcmOriginToTransactDispatchOrigin;
-//     type WeightInfo = (); // Configure based on benchmarking results.
-//     type PriceForSiblingDelivery = NoPriceForMessageDelivery<ParaId>;
-//     // Limit the number of messages and signals a HRML channel can have at most
-//     type MaxActiveOutboundChannels = ConstU32<128>;
-//     // Limit the number of HRML channels
-//     type MaxPageSize = ConstU32<{ 1 << 16 }>;
-// }
+impl pallet_assets::Config<TrustBackedAssetsInstance> for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type RemoveItemsLimit = ConstU32<1000>;
+    type AssetId = u32;
+    type AssetIdParameter = codec::Compact<u32>;
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+    type ForceOrigin = EnsureRootOrHalfCouncil;
+    type AssetDeposit = AssetDeposit;
+    type AssetAccountDeposit = AssetAccountDeposit;
+    type MetadataDepositBase = MetadataDepositBase;
+    type MetadataDepositPerByte = MetadataDepositPerByte;
+    type ApprovalDeposit = ApprovalDeposit;
+    type StringLimit = AssetsStringLimit;
+    type Freezer = ();
+    type Extra = ();
+    type CallbackHandle = ();
+    type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+}
+
+impl pallet_sudo::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+    type WeightInfo = crate::weights::pallet_sudo::WeightInfo<Runtime>;
+}
 
 parameter_types! {
     pub const Period: u32 = 6 * HOURS;
@@ -359,18 +344,20 @@ impl pallet_session::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type ValidatorId = <Self as frame_system::Config>::AccountId;
     // we don't have stash and controller, thus we don't need the convert as well.
-    // TODO: Configure pallet
-    // type ValidatorIdOf = pallet_collator_selection::IdentityCollator;
+    #[cfg(feature = "solochain")]
     type ValidatorIdOf = ();
+    #[cfg(feature = "parachain")]
+    type ValidatorIdOf = pallet_collator_selection::IdentityCollator;
     type ShouldEndSession = pallet_session::PeriodicSessions<Period, Offset>;
     type NextSessionRotation = pallet_session::PeriodicSessions<Period, Offset>;
-    // TODO: Configure pallet
+    #[cfg(feature = "solochain")]
     type SessionManager = ();
-    // type SessionManager = CollatorSelection;
+    #[cfg(feature = "parachain")]
+    type SessionManager = CollatorSelection;
     // Essentially just Aura, but let's be pedantic.
     type SessionHandler = <SessionKeys as sp_runtime::traits::OpaqueKeys>::KeyTypeIdProviders;
     type Keys = SessionKeys;
-    type WeightInfo = (); // Configure based on benchmarking results.
+    type WeightInfo = crate::weights::pallet_session::WeightInfo<Runtime>;
 }
 
 impl pallet_aura::Config for Runtime {
@@ -382,33 +369,60 @@ impl pallet_aura::Config for Runtime {
 }
 
 parameter_types! {
-    pub const PotId: PalletId = PalletId(*b"PotStake");
     pub const SessionLength: BlockNumber = 6 * HOURS;
-    // StakingAdmin pluralistic body.
-    // TODO: Configure variable
-    // pub const StakingAdminBodyId: BodyId = BodyId::Defense;
 }
 
-// TODO: Configure pallet
-// /// We allow root and the StakingAdmin to execute privileged collator selection operations.
-// pub type CollatorSelectionUpdateOrigin = EitherOfDiverse<
-//     EnsureRoot<AccountId>,
-//     EnsureXcm<IsVoiceOfBody<RelayLocation, StakingAdminBodyId>>,
-// >;
-
-// TODO: Configure pallet
-// impl pallet_collator_selection::Config for Runtime {
-//     type RuntimeEvent = RuntimeEvent;
-//     type Currency = Balances;
-//     type UpdateOrigin = CollatorSelectionUpdateOrigin;
-//     type PotId = PotId;
-//     type MaxCandidates = ConstU32<100>;
-//     type MinEligibleCollators = ConstU32<4>;
-//     type MaxInvulnerables = ConstU32<20>;
-//     // should be a multiple of session or things will get inconsistent
-//     type KickThreshold = Period;
-//     type ValidatorId = <Self as frame_system::Config>::AccountId;
-//     type ValidatorIdOf = pallet_collator_selection::IdentityCollator;
-//     type ValidatorRegistration = Session;
-//     type WeightInfo = (); // Configure based on benchmarking results.
-// }
+parameter_types! {
+    pub const TeamRegistryBasicDeposit: Balance = 10 * CENTIUNIT;
+    pub const TeamRegistryByteDeposit: Balance = MILLIUNIT;
+}
+
+impl pallet_team_registry::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type BasicDeposit = TeamRegistryBasicDeposit;
+    type ByteDeposit = TeamRegistryByteDeposit;
+    type MaxDisplayNameLength = ConstU32<64>;
+    type MaxDiscordLength = ConstU32<32>;
+    type MaxRegistrars = ConstU32<20>;
+    type RegistrarOrigin = EnsureRootOrHalfCouncil;
+    type WeightInfo = pallet_team_registry::weights::SubstrateWeight<Runtime>;
+}
+
+impl pallet_utility::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+    type PalletsOrigin = OriginCaller;
+    type WeightInfo = pallet_utility::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    // One storage item plus the encoded size of a legacy identity with every field set.
+    pub const IdentityBasicDeposit: Balance = deposit(1, 258);
+    pub const IdentityByteDeposit: Balance = deposit(0, 1);
+    pub const SubAccountDeposit: Balance = deposit(1, 53);
+    pub const MaxAdditionalFields: u32 = 100;
+}
+
+impl pallet_identity::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type BasicDeposit = IdentityBasicDeposit;
+    type ByteDeposit = IdentityByteDeposit;
+    type SubAccountDeposit = SubAccountDeposit;
+    type MaxSubAccounts = ConstU32<100>;
+    type IdentityInformation = pallet_identity::legacy::IdentityInfo<MaxAdditionalFields>;
+    type MaxRegistrars = ConstU32<20>;
+    // TODO: Send slashed deposits to the treasury once it is part of the runtime.
+    type Slashed = ();
+    type ForceOrigin = EnsureRootOrHalfCouncil;
+    type RegistrarOrigin = EnsureRootOrHalfCouncil;
+    type OffchainSignature = Signature;
+    type SigningPublicKey = <Signature as Verify>::Signer;
+    type UsernameAuthorityOrigin = EnsureRoot<AccountId>;
+    type PendingUsernameExpiration = ConstU32<{ 7 * DAYS }>;
+    type MaxSuffixLength = ConstU32<7>;
+    type MaxUsernameLength = ConstU32<32>;
+    type WeightInfo = pallet_identity::weights::SubstrateWeight<Runtime>;
+}
@@ -23,31 +23,34 @@
 //
 // For more information, please refer to <http://unlicense.org>
 
+use super::{ForeignAssetsInstance, TreasuryAccount};
 use crate::{
-    AccountId, AllPalletsWithSystem, Balances, ParachainInfo, ParachainSystem, PolkadotXcm,
-    Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, WeightToFee, XcmpQueue,
+    AccountId, AllPalletsWithSystem, Assets, Balance, Balances, ForeignAssets, ParachainInfo,
+    ParachainSystem, PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, WeightToFee,
+    XcmpQueue,
 };
 use frame_support::{
     parameter_types,
-    traits::{ConstU32, Contains, Everything, Nothing},
-    weights::Weight,
+    traits::{ConstU32, Contains, Equals, Everything, Nothing, PalletInfoAccess},
 };
 use frame_system::EnsureRoot;
 use pallet_xcm::XcmPassthrough;
-use parachains_common::TREASURY_PALLET_ID;
+use pallet_xcm_trust::{IsTrustedDestination, IsTrustedOrigin, IsTrustedReserve};
+use parachains_common::xcm_config::AssetFeeAsExistentialDepositMultiplier;
 use polkadot_parachain_primitives::primitives::Sibling;
 use polkadot_runtime_common::impls::ToAuthor;
-use sp_runtime::traits::AccountIdConversion;
+use sp_runtime::traits::{ConvertInto, Identity};
 use xcm::latest::prelude::*;
 use xcm_builder::{
     AccountId32Aliases, AllowExplicitUnpaidExecutionFrom, AllowTopLevelPaidExecutionFrom,
-    DenyReserveTransferToRelayChain, DenyThenTry, EnsureXcmOrigin, FixedWeightBounds,
-    FrameTransactionalProcessor, FungibleAdapter, IsConcrete, NativeAsset, ParentIsPreset,
-    RelayChainAsNative, SiblingParachainAsNative, SiblingParachainConvertsVia,
-    SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation, TakeWeightCredit,
-    TrailingSetTopicAsId, UsingComponents, WithComputedOrigin, WithUniqueTopic,
+    AsPrefixedGeneralIndex, ConvertedConcreteId, DenyReserveTransferToRelayChain, DenyThenTry,
+    EnsureXcmOrigin, FrameTransactionalProcessor, FungibleAdapter, FungiblesAdapter, IsConcrete,
+    MatchedConvertedConcreteId, NoChecking, ParentIsPreset, RelayChainAsNative,
+    SiblingParachainAsNative, SiblingParachainConvertsVia, SignedAccountId32AsNative,
+    SignedToAccountId32, SovereignSignedViaLocation, StartsWith, TakeWeightCredit,
+    TrailingSetTopicAsId, UsingComponents, WeightInfoBounds, WithComputedOrigin, WithUniqueTopic,
 };
-use xcm_executor::XcmExecutor;
+use xcm_executor::{traits::JustTry, XcmExecutor};
 
 parameter_types! {
     pub const RelayLocation: Location = Location::parent();
@@ -57,7 +60,13 @@ parameter_types! {
     // For the real deployment, it is recommended to set `RelayNetwork` according to the relay chain
     // and prepend `UniversalLocation` with `GlobalConsensus(RelayNetwork::get())`.
     pub UniversalLocation: InteriorLocation = Parachain(ParachainInfo::parachain_id().into()).into();
-    pub TreasuryAccount: AccountId = TREASURY_PALLET_ID.into_account_truncating();
+    pub AssetsPalletLocation: Location =
+        PalletInstance(<Assets as PalletInfoAccess>::index() as u8).into();
+    pub CheckingAccount: AccountId = PolkadotXcm::check_account();
+    /// USDT, asset `1984` of the Asset Hub trust backed assets pallet.
+    pub UsdtFromAssetHub: Location =
+        Location::new(1, [Parachain(1000), PalletInstance(50), GeneralIndex(1984)]);
+    pub XcmFeesReceiver: Option<AccountId> = Some(TreasuryAccount::get());
 }
 
 /// Type for specifying how a `Location` can be converted into an `AccountId`. This is used
@@ -86,6 +95,69 @@ pub type LocalAssetTransactor = FungibleAdapter<
     (),
 >;
 
+/// Matches the local assets of `pallet_assets`, at `(0, [PalletInstance(_), GeneralIndex(id)])`.
+pub type TrustBackedAssetsConvertedConcreteId = ConvertedConcreteId<
+    u32,
+    Balance,
+    AsPrefixedGeneralIndex<AssetsPalletLocation, u32, JustTry>,
+    JustTry,
+>;
+
+/// Means for transacting the local assets of `pallet_assets`.
+pub type LocalFungiblesTransactor = FungiblesAdapter<
+    Assets,
+    TrustBackedAssetsConvertedConcreteId,
+    LocationToAccountId,
+    AccountId,
+    // We don't track any teleports.
+    NoChecking,
+    CheckingAccount,
+>;
+
+/// Matches the assets of other consensus systems, identified by their location.
+pub type ForeignAssetsConvertedConcreteId =
+    MatchedConvertedConcreteId<Location, Balance, StartsWith<RelayLocation>, Identity, JustTry>;
+
+/// Means for transacting foreign assets, e.g. USDT reserve transferred from Asset Hub.
+pub type ForeignFungiblesTransactor = FungiblesAdapter<
+    ForeignAssets,
+    ForeignAssetsConvertedConcreteId,
+    LocationToAccountId,
+    AccountId,
+    // We don't track any teleports.
+    NoChecking,
+    CheckingAccount,
+>;
+
+/// Means for transacting assets on this chain, tried in order.
+pub type AssetTransactors = (
+    LocalAssetTransactor,
+    LocalFungiblesTransactor,
+    ForeignFungiblesTransactor,
+);
+
+/// Foreign assets that can be used to pay for XCM execution.
+pub type XcmFeeAssets = Equals<UsdtFromAssetHub>;
+
+/// Charges XCM execution in the whitelisted foreign assets, proportionally to their minimum
+/// balance. The fees go to the treasury.
+pub type ForeignAssetsTrader = cumulus_primitives_utility::TakeFirstAssetTrader<
+    AccountId,
+    AssetFeeAsExistentialDepositMultiplier<
+        Runtime,
+        WeightToFee,
+        pallet_assets::BalanceToAssetBalance<Balances, Runtime, ConvertInto, ForeignAssetsInstance>,
+        ForeignAssetsInstance,
+    >,
+    MatchedConvertedConcreteId<Location, Balance, XcmFeeAssets, Identity, JustTry>,
+    ForeignAssets,
+    cumulus_primitives_utility::XcmFeesTo32ByteAccount<
+        ForeignFungiblesTransactor,
+        AccountId,
+        XcmFeesReceiver,
+    >,
+>;
+
 /// This is the type we use to convert an (incoming) XCM origin into a local `Origin` instance,
 /// ready for dispatching a transaction with Xcm's `Transact`. There is an `OriginKind` which can
 /// biases the kind of local `Origin` it will become.
@@ -108,8 +180,6 @@ pub type XcmOriginToTransactDispatchOrigin = (
 );
 
 parameter_types! {
-    // One XCM operation is 1_000_000_000 weight - almost certainly a conservative estimate.
-    pub UnitWeightCost: Weight = Weight::from_parts(1_000_000_000, 64 * 1024);
     pub const MaxInstructions: u32 = 100;
     pub const MaxAssetsIntoHolding: u32 = 64;
 }
@@ -138,7 +208,8 @@ pub type Barrier = TrailingSetTopicAsId<
             TakeWeightCredit,
             WithComputedOrigin<
                 (
-                    AllowTopLevelPaidExecutionFrom<Everything>,
+                    // Only the relay chain and the chains trusted by `XcmTrust`.
+                    AllowTopLevelPaidExecutionFrom<IsTrustedOrigin<Runtime>>,
                     AllowExplicitUnpaidExecutionFrom<ParentOrParentsExecutivePlurality>,
                     // ^^^ Parent and its exec plurality get free execution
                 ),
@@ -154,15 +225,17 @@ impl xcm_executor::Config for XcmConfig {
     type RuntimeCall = RuntimeCall;
     type XcmSender = XcmRouter;
     // How to withdraw and deposit an asset.
-    type AssetTransactor = LocalAssetTransactor;
+    type AssetTransactor = AssetTransactors;
     type OriginConverter = XcmOriginToTransactDispatchOrigin;
-    type IsReserve = NativeAsset;
+    type IsReserve = IsTrustedReserve<Runtime>;
     type IsTeleporter = (); // Teleporting is disabled.
     type UniversalLocation = UniversalLocation;
     type Barrier = Barrier;
-    type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
-    type Trader =
-        UsingComponents<WeightToFee, RelayLocation, AccountId, Balances, ToAuthor<Runtime>>;
+    type Weigher = XcmWeigher;
+    type Trader = (
+        UsingComponents<WeightToFee, RelayLocation, AccountId, Balances, ToAuthor<Runtime>>,
+        ForeignAssetsTrader,
+    );
     type ResponseHandler = PolkadotXcm;
     type AssetTrap = PolkadotXcm;
     type AssetClaims = PolkadotXcm;
@@ -184,6 +257,10 @@ impl xcm_executor::Config for XcmConfig {
     type XcmRecorder = PolkadotXcm;
 }
 
+/// Weighs XCM messages with the benchmarked weight of each instruction.
+pub type XcmWeigher =
+    WeightInfoBounds<crate::weights::xcm::XcmWeight<RuntimeCall>, RuntimeCall, MaxInstructions>;
+
 /// No local origins on this chain are allowed to dispatch XCM sends/executions.
 pub type LocalOriginToLocation = SignedToAccountId32<RuntimeOrigin, AccountId, RelayNetwork>;
 
@@ -206,8 +283,8 @@ impl pallet_xcm::Config for Runtime {
     // Needs to be `Everything` for local testing.
     type XcmExecutor = XcmExecutor<XcmConfig>;
     type XcmTeleportFilter = Everything;
-    type XcmReserveTransferFilter = Nothing;
-    type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+    type XcmReserveTransferFilter = IsTrustedDestination<Runtime>;
+    type Weigher = XcmWeigher;
     type UniversalLocation = UniversalLocation;
     type RuntimeOrigin = RuntimeOrigin;
     type RuntimeCall = RuntimeCall;
@@ -220,7 +297,7 @@ impl pallet_xcm::Config for Runtime {
     type TrustedLockers = ();
     type SovereignAccountOf = LocationToAccountId;
     type MaxLockers = ConstU32<8>;
-    type WeightInfo = pallet_xcm::TestWeightInfo; // Configure based on benchmarking results.
+    type WeightInfo = crate::weights::pallet_xcm::WeightInfo<Runtime>;
     type AdminOrigin = EnsureRoot<AccountId>;
     type MaxRemoteLockConsumers = ConstU32<0>;
     type RemoteLockConsumerIdentifier = ();
@@ -230,3 +307,11 @@ impl cumulus_pallet_xcm::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type XcmExecutor = XcmExecutor<XcmConfig>;
 }
+
+impl pallet_xcm_trust::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type AdminOrigin = EnsureRoot<AccountId>;
+    type MaxTrustedParachains = ConstU32<100>;
+    type MaxTrustedReserves = ConstU32<20>;
+    type WeightInfo = pallet_xcm_trust::weights::SubstrateWeight<Runtime>;
+}
@@ -0,0 +1,89 @@
+// This is free and unencumbered software released into the public domain.
+//
+// Anyone is free to copy, modify, publish, use, compile, sell, or
+// distribute this software, either in source code form or as a compiled
+// binary, for any purpose, commercial or non-commercial, and by any
+// means.
+//
+// In jurisdictions that recognize copyright laws, the author or authors
+// of this software dedicate any and all copyright interest in the
+// software to the public domain. We make this dedication for the benefit
+// of the public at large and to the detriment of our heirs and
+// successors. We intend this dedication to be an overt act of
+// relinquishment in perpetuity of all present and future rights to this
+// software under copyright law.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.
+// IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY CLAIM, DAMAGES OR
+// OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE,
+// ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+//
+// For more information, please refer to <http://unlicense.org>
+
+//! Helper types used by the pallet configurations.
+
+use core::marker::PhantomData;
+
+use frame_support::traits::{
+    fungible::{Balanced, Credit},
+    Get, Imbalance, OnUnbalanced,
+};
+use sp_runtime::Percent;
+
+#[cfg(feature = "solochain")]
+use crate::Authorship;
+#[cfg(feature = "parachain")]
+use crate::CollatorSelection;
+use crate::{configs::TreasuryAccount, AccountId, Balances};
+
+/// Splits transaction fees and tips between the treasury and the collators.
+///
+/// `TreasuryShare` of the fees and tips goes to the treasury and the rest to the collators: on the
+/// parachain into the collator selection pot, which pays half of its balance to the author of every
+/// block, and on the solochain to the author of the block. If there is no such account, everything
+/// goes to the treasury.
+pub struct DealWithFees<TreasuryShare>(PhantomData<TreasuryShare>);
+
+impl<TreasuryShare: Get<Percent>> OnUnbalanced<Credit<AccountId, Balances>>
+    for DealWithFees<TreasuryShare>
+{
+    fn on_unbalanceds<B>(mut fees_then_tips: impl Iterator<Item = Credit<AccountId, Balances>>) {
+        let Some(mut fees) = fees_then_tips.next() else {
+            return;
+        };
+        if let Some(tips) = fees_then_tips.next() {
+            tips.merge_into(&mut fees);
+        }
+
+        let treasury_share = TreasuryShare::get().deconstruct() as u32;
+        let (mut to_treasury, to_collators) = fees.ration(treasury_share, 100 - treasury_share);
+        match collators_account() {
+            Some(collators) => resolve(&collators, to_collators),
+            None => to_treasury.subsume(to_collators),
+        }
+        resolve(&TreasuryAccount::get(), to_treasury);
+    }
+}
+
+/// The account receiving the collators' share of the fees.
+#[cfg(feature = "parachain")]
+fn collators_account() -> Option<AccountId> {
+    Some(CollatorSelection::account_id())
+}
+
+/// The account receiving the collators' share of the fees.
+#[cfg(feature = "solochain")]
+fn collators_account() -> Option<AccountId> {
+    Authorship::author()
+}
+
+/// Deposit `credit` into the account of `who`.
+///
+/// This only fails if the credit is below the existential deposit of a dead account, in which
+/// case it is burnt.
+fn resolve(who: &AccountId, credit: Credit<AccountId, Balances>) {
+    let _ = <Balances as Balanced<AccountId>>::resolve(who, credit);
+}
@@ -27,6 +27,9 @@
 // `construct_runtime!` does a lot of recursion and requires us to increase the limit to 256.
 #![recursion_limit = "256"]
 
+#[cfg(not(any(feature = "solochain", feature = "parachain")))]
+compile_error!("Either the `solochain` or the `parachain` feature must be enabled.");
+
 // Make the WASM binary available.
 #[cfg(feature = "std")]
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
@@ -35,6 +38,8 @@ pub mod apis;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarks;
 mod configs;
+mod impls;
+mod migrations;
 mod weights;
 
 use smallvec::smallvec;
@@ -50,10 +55,7 @@ use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
 
 use frame_support::weights::{
-    // constants::WEIGHT_REF_TIME_PER_SECOND,
-    // Weight,
-    WeightToFeeCoefficient,
-    WeightToFeeCoefficients,
+    constants::WEIGHT_REF_TIME_PER_SECOND, Weight, WeightToFeeCoefficient, WeightToFeeCoefficients,
     WeightToFeePolynomial,
 };
 pub use sp_consensus_aura::sr25519::AuthorityId as AuraId;
@@ -99,6 +101,21 @@ pub type SignedBlock = generic::SignedBlock<Block>;
 pub type BlockId = generic::BlockId<Block>;
 
 /// The SignedExtension to the basic transaction logic.
+#[cfg(feature = "solochain")]
+pub type SignedExtra = (
+    frame_system::CheckNonZeroSender<Runtime>,
+    frame_system::CheckSpecVersion<Runtime>,
+    frame_system::CheckTxVersion<Runtime>,
+    frame_system::CheckGenesis<Runtime>,
+    frame_system::CheckEra<Runtime>,
+    frame_system::CheckNonce<Runtime>,
+    frame_system::CheckWeight<Runtime>,
+    pallet_asset_conversion_tx_payment::ChargeAssetTxPayment<Runtime>,
+    frame_metadata_hash_extension::CheckMetadataHash<Runtime>,
+);
+
+/// The SignedExtension to the basic transaction logic.
+#[cfg(feature = "parachain")]
 #[docify::export(template_signed_extra)]
 pub type SignedExtra = (
     frame_system::CheckNonZeroSender<Runtime>,
@@ -108,8 +125,8 @@ pub type SignedExtra = (
     frame_system::CheckEra<Runtime>,
     frame_system::CheckNonce<Runtime>,
     frame_system::CheckWeight<Runtime>,
-    pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
-    // TODO: Configure signed extension for storage weight reclaim for Cumulus Parachain block.
+    pallet_asset_conversion_tx_payment::ChargeAssetTxPayment<Runtime>,
+    cumulus_primitives_storage_weight_reclaim::StorageWeightReclaim<Runtime>,
     frame_metadata_hash_extension::CheckMetadataHash<Runtime>,
 );
 
@@ -117,9 +134,13 @@ pub type SignedExtra = (
 pub type UncheckedExtrinsic =
     generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
 
-/// Migrations to apply on runtime upgrade.
-// TODO: Configure pallet
-// pub type Migrations = pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>;
+/// The payload being signed in transactions.
+pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
+
+pub use configs::NativeOrAssetId;
+#[cfg(feature = "parachain")]
+pub use configs::PotId;
+pub use migrations::Migrations;
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
@@ -128,7 +149,7 @@ pub type Executive = frame_executive::Executive<
     frame_system::ChainContext<Runtime>,
     Runtime,
     AllPalletsWithSystem,
-    // Migrations,
+    Migrations,
 >;
 
 /// Handles converting a weight scalar to a fee value, based on the scale and granularity of the
@@ -221,36 +242,54 @@ pub const CENTIUNIT: Balance = 10_000_000_000;
 pub const MILLIUNIT: Balance = 1_000_000_000;
 pub const MICROUNIT: Balance = 1_000_000;
 
+/// Deposit required to store `items` storage items taking up `bytes` bytes in total.
+pub const fn deposit(items: u32, bytes: u32) -> Balance {
+    items as Balance * UNIT + (bytes as Balance) * MILLIUNIT
+}
+
 /// The existential deposit. Set to 1/10 of the Connected Relay Chain.
 pub const EXISTENTIAL_DEPOSIT: Balance = MILLIUNIT;
 
-// TODO: Configure variable
-// /// We assume that ~5% of the block weight is consumed by `on_initialize` handlers. This is
-// /// used to limit the maximal weight of a single extrinsic.
-// const AVERAGE_ON_INITIALIZE_RATIO: Perbill = Perbill::from_percent(5);
+/// We assume that ~5% of the block weight is consumed by `on_initialize` handlers. This is
+/// used to limit the maximal weight of a single extrinsic.
+const AVERAGE_ON_INITIALIZE_RATIO: Perbill = Perbill::from_percent(5);
 
 /// We allow `Normal` extrinsics to fill up the block up to 75%, the rest can be used by
 /// `Operational` extrinsics.
 const NORMAL_DISPATCH_RATIO: Perbill = Perbill::from_percent(75);
 
-// TODO: Configure variable
-// /// We allow for 2 seconds of compute with a 6-second average block.
-// const MAXIMUM_BLOCK_WEIGHT: Weight = Weight::from_parts(
-//     WEIGHT_REF_TIME_PER_SECOND.saturating_mul(2),
-//     cumulus_primitives_core::relay_chain::MAX_POV_SIZE as u64,
-// );
-
-// TODO: Constant variables for the consensus hook
-// /// Maximum number of blocks simultaneously accepted by the Runtime, not yet included
-// /// into the relay chain.
-// const UNINCLUDED_SEGMENT_CAPACITY: u32 = 3;
-// /// How many parachain blocks are processed by the relay chain per parent. Limits the
-// /// number of blocks authored per slot.
-// const BLOCK_PROCESSING_VELOCITY: u32 = 1;
-// /// Relay chain slot duration, in milliseconds.
-// const RELAY_CHAIN_SLOT_DURATION_MILLIS: u32 = 6000;
-
-// TODO: Configure Cumulus Aura consensus hook
+/// We allow for 2 seconds of compute with a 6-second average block.
+#[cfg(feature = "parachain")]
+const MAXIMUM_BLOCK_WEIGHT: Weight = Weight::from_parts(
+    WEIGHT_REF_TIME_PER_SECOND.saturating_mul(2),
+    cumulus_primitives_core::relay_chain::MAX_POV_SIZE as u64,
+);
+
+/// We allow for 2 seconds of compute with a 6-second average block. A solochain has no PoV
+/// limit, so the proof size is left unbounded.
+#[cfg(feature = "solochain")]
+const MAXIMUM_BLOCK_WEIGHT: Weight =
+    Weight::from_parts(WEIGHT_REF_TIME_PER_SECOND.saturating_mul(2), u64::MAX);
+
+/// Maximum number of blocks simultaneously accepted by the Runtime, not yet included
+/// into the relay chain.
+#[cfg(feature = "parachain")]
+const UNINCLUDED_SEGMENT_CAPACITY: u32 = 3;
+/// How many parachain blocks are processed by the relay chain per parent. Limits the
+/// number of blocks authored per slot.
+#[cfg(feature = "parachain")]
+const BLOCK_PROCESSING_VELOCITY: u32 = 1;
+/// Relay chain slot duration, in milliseconds.
+#[cfg(feature = "parachain")]
+const RELAY_CHAIN_SLOT_DURATION_MILLIS: u32 = 6000;
+
+#[cfg(feature = "parachain")]
+type ConsensusHook = cumulus_pallet_aura_ext::FixedVelocityConsensusHook<
+    Runtime,
+    RELAY_CHAIN_SLOT_DURATION_MILLIS,
+    BLOCK_PROCESSING_VELOCITY,
+    UNINCLUDED_SEGMENT_CAPACITY,
+>;
 
 /// The version information used to identify this runtime when compiled natively.
 #[cfg(feature = "std")]
@@ -261,6 +300,9 @@ pub fn native_version() -> NativeVersion {
     }
 }
 
+// `#[frame_support::runtime]` does not support `#[cfg]` on individual pallets, so each flavour
+// declares its own runtime. Keep the pallets they share at the same index.
+#[cfg(feature = "parachain")]
 #[frame_support::runtime]
 mod runtime {
     // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -281,19 +323,30 @@ mod runtime {
     // System support stuff.
     #[runtime::pallet_index(0)]
     pub type System = frame_system::Pallet<Runtime>;
-
-    // TODO: Configure Parachain System (Index = 1)
-
+    #[runtime::pallet_index(1)]
+    pub type ParachainSystem = cumulus_pallet_parachain_system::Pallet<Runtime>;
     #[runtime::pallet_index(2)]
     pub type Timestamp = pallet_timestamp::Pallet<Runtime>;
-
-    // TODO: Configure Parachain Info (Index = 2)
+    #[runtime::pallet_index(3)]
+    pub type ParachainInfo = parachain_info::Pallet<Runtime>;
 
     // Monetary stuff.
     #[runtime::pallet_index(10)]
     pub type Balances = pallet_balances::Pallet<Runtime>;
     #[runtime::pallet_index(11)]
     pub type TransactionPayment = pallet_transaction_payment::Pallet<Runtime>;
+    #[runtime::pallet_index(12)]
+    pub type Treasury = pallet_treasury::Pallet<Runtime>;
+    #[runtime::pallet_index(13)]
+    pub type Assets = pallet_assets::Pallet<Runtime, Instance1>;
+    #[runtime::pallet_index(14)]
+    pub type ForeignAssets = pallet_assets::Pallet<Runtime, Instance2>;
+    #[runtime::pallet_index(16)]
+    pub type PoolAssets = pallet_assets::Pallet<Runtime, Instance3>;
+    #[runtime::pallet_index(17)]
+    pub type AssetConversion = pallet_asset_conversion::Pallet<Runtime>;
+    #[runtime::pallet_index(18)]
+    pub type AssetTxPayment = pallet_asset_conversion_tx_payment::Pallet<Runtime>;
 
     // Governance
     #[runtime::pallet_index(15)]
@@ -303,27 +356,134 @@ mod runtime {
     #[runtime::pallet_index(20)]
     pub type Authorship = pallet_authorship::Pallet<Runtime>;
 
-    // TODO: Configure Collator Selection (Index = 21)
+    #[cfg(feature = "parachain")]
+    #[runtime::pallet_index(21)]
+    pub type CollatorSelection = pallet_collator_selection::Pallet<Runtime>;
 
     #[runtime::pallet_index(22)]
     pub type Session = pallet_session::Pallet<Runtime>;
     #[runtime::pallet_index(23)]
     pub type Aura = pallet_aura::Pallet<Runtime>;
+    #[runtime::pallet_index(24)]
+    pub type AuraExt = cumulus_pallet_aura_ext::Pallet<Runtime>;
 
-    // TODO: Configure Cumulus Pallet Aura Extension (Index = 24)
+    // XCM helpers.
+    #[runtime::pallet_index(30)]
+    pub type XcmpQueue = cumulus_pallet_xcmp_queue::Pallet<Runtime>;
+    #[runtime::pallet_index(31)]
+    pub type PolkadotXcm = pallet_xcm::Pallet<Runtime>;
+    #[runtime::pallet_index(32)]
+    pub type CumulusXcm = cumulus_pallet_xcm::Pallet<Runtime>;
+    #[runtime::pallet_index(33)]
+    pub type MessageQueue = pallet_message_queue::Pallet<Runtime>;
+    #[runtime::pallet_index(34)]
+    pub type XcmTrust = pallet_xcm_trust::Pallet<Runtime>;
+
+    // Utilities.
+    #[runtime::pallet_index(40)]
+    pub type Utility = pallet_utility::Pallet<Runtime>;
+    #[runtime::pallet_index(41)]
+    pub type Identity = pallet_identity::Pallet<Runtime>;
+
+    // Custom pallets.
+    #[runtime::pallet_index(50)]
+    pub type TeamRegistry = pallet_team_registry::Pallet<Runtime>;
+
+    // On-chain governance, replacing `Sudo` once the council is seated.
+    #[runtime::pallet_index(60)]
+    pub type Scheduler = pallet_scheduler::Pallet<Runtime>;
+    #[runtime::pallet_index(61)]
+    pub type Preimage = pallet_preimage::Pallet<Runtime>;
+    #[runtime::pallet_index(62)]
+    pub type Council = pallet_collective::Pallet<Runtime, Instance1>;
+    #[runtime::pallet_index(63)]
+    pub type ConvictionVoting = pallet_conviction_voting::Pallet<Runtime>;
+    #[runtime::pallet_index(64)]
+    pub type Referenda = pallet_referenda::Pallet<Runtime>;
+}
 
-    #[runtime::pallet_index(25)]
-    pub type Grandpa = pallet_grandpa::Pallet<Runtime>;
+#[cfg(feature = "solochain")]
+#[frame_support::runtime]
+mod runtime {
+    // Create the runtime by composing the FRAME pallets that were previously configured.
+    #[runtime::runtime]
+    #[runtime::derive(
+        RuntimeCall,
+        RuntimeEvent,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeHoldReason,
+        RuntimeSlashReason,
+        RuntimeLockId,
+        RuntimeTask
+    )]
+    pub struct Runtime;
 
-    // XCM helpers.
+    // System support stuff.
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system::Pallet<Runtime>;
+    #[runtime::pallet_index(2)]
+    pub type Timestamp = pallet_timestamp::Pallet<Runtime>;
 
-    // TODO: Configure XCMP Queue as a message queue for XCM instructions (Index = 30)
+    // Monetary stuff.
+    #[runtime::pallet_index(10)]
+    pub type Balances = pallet_balances::Pallet<Runtime>;
+    #[runtime::pallet_index(11)]
+    pub type TransactionPayment = pallet_transaction_payment::Pallet<Runtime>;
+    #[runtime::pallet_index(12)]
+    pub type Treasury = pallet_treasury::Pallet<Runtime>;
+    #[runtime::pallet_index(13)]
+    pub type Assets = pallet_assets::Pallet<Runtime, Instance1>;
+    #[runtime::pallet_index(16)]
+    pub type PoolAssets = pallet_assets::Pallet<Runtime, Instance3>;
+    #[runtime::pallet_index(17)]
+    pub type AssetConversion = pallet_asset_conversion::Pallet<Runtime>;
+    #[runtime::pallet_index(18)]
+    pub type AssetTxPayment = pallet_asset_conversion_tx_payment::Pallet<Runtime>;
+
+    // Governance
+    #[runtime::pallet_index(15)]
+    pub type Sudo = pallet_sudo;
 
-    // TODO: Configure XCM module (Index = 31)
+    // Block authoring. The indices match the parachain runtime, so that the solochain state can
+    // be migrated into a parachain genesis.
+    #[runtime::pallet_index(20)]
+    pub type Authorship = pallet_authorship::Pallet<Runtime>;
+    #[runtime::pallet_index(22)]
+    pub type Session = pallet_session::Pallet<Runtime>;
+    #[runtime::pallet_index(23)]
+    pub type Aura = pallet_aura::Pallet<Runtime>;
 
-    // TODO: Configure Cumulus XCM module (Index = 32)
+    // Solochain finality. Parachains are finalized by the relay chain instead.
+    #[runtime::pallet_index(25)]
+    pub type Grandpa = pallet_grandpa::Pallet<Runtime>;
 
-    // TODO: Configure Message Queue (Index = 33)
+    // Utilities.
+    #[runtime::pallet_index(40)]
+    pub type Utility = pallet_utility::Pallet<Runtime>;
+    #[runtime::pallet_index(41)]
+    pub type Identity = pallet_identity::Pallet<Runtime>;
+
+    // Custom pallets.
+    #[runtime::pallet_index(50)]
+    pub type TeamRegistry = pallet_team_registry::Pallet<Runtime>;
+
+    // On-chain governance, replacing `Sudo` once the council is seated.
+    #[runtime::pallet_index(60)]
+    pub type Scheduler = pallet_scheduler::Pallet<Runtime>;
+    #[runtime::pallet_index(61)]
+    pub type Preimage = pallet_preimage::Pallet<Runtime>;
+    #[runtime::pallet_index(62)]
+    pub type Council = pallet_collective::Pallet<Runtime, Instance1>;
+    #[runtime::pallet_index(63)]
+    pub type ConvictionVoting = pallet_conviction_voting::Pallet<Runtime>;
+    #[runtime::pallet_index(64)]
+    pub type Referenda = pallet_referenda::Pallet<Runtime>;
 }
 
-// TODO: Register validate block
+#[cfg(feature = "parachain")]
+cumulus_pallet_parachain_system::register_validate_block! {
+    Runtime = Runtime,
+    BlockExecutor = cumulus_pallet_aura_ext::BlockExecutor::<Runtime, Executive>,
+}
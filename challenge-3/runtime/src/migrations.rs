@@ -0,0 +1,110 @@
+// This is free and unencumbered software released into the public domain.
+//
+// Anyone is free to copy, modify, publish, use, compile, sell, or
+// distribute this software, either in source code form or as a compiled
+// binary, for any purpose, commercial or non-commercial, and by any
+// means.
+//
+// In jurisdictions that recognize copyright laws, the author or authors
+// of this software dedicate any and all copyright interest in the
+// software to the public domain. We make this dedication for the benefit
+// of the public at large and to the detriment of our heirs and
+// successors. We intend this dedication to be an overt act of
+// relinquishment in perpetuity of all present and future rights to this
+// software under copyright law.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.
+// IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY CLAIM, DAMAGES OR
+// OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE,
+// ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+//
+// For more information, please refer to <http://unlicense.org>
+
+//! Migrations applied by the runtime on upgrade.
+
+use frame_support::{
+    storage::migration::take_storage_value,
+    traits::{OnRuntimeUpgrade, PalletInfoAccess},
+    weights::Weight,
+};
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+use crate::{configs::CouncilCollective, AccountId, Runtime, Sudo};
+
+const LOG_TARGET: &str = "runtime::migrations";
+
+/// Migrations to apply on runtime upgrade, in order.
+///
+/// A `spec_version` bump that changes the layout of some storage appends its migration here.
+/// Pallet migrations are `VersionedMigration`s that only run when the on-chain storage version
+/// matches, so they can stay in the list until every live chain has been upgraded.
+#[cfg(feature = "solochain")]
+pub type Migrations = (
+    DisableSudo,
+    pallet_team_registry::migrations::v1::MigrateV0ToV1<Runtime>,
+);
+
+/// Migrations to apply on runtime upgrade, in order.
+#[cfg(feature = "parachain")]
+pub type Migrations = (
+    DisableSudo,
+    pallet_team_registry::migrations::v1::MigrateV0ToV1<Runtime>,
+    // Keep the stored XCM versions and locations up to date with every `xcm` bump.
+    pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,
+);
+
+/// Removes the `Sudo` key once the council is seated.
+///
+/// Until then the upgrade is a no-op, so the key stays usable to bootstrap governance. With the
+/// key removed, root can only be reached through a referendum.
+pub struct DisableSudo;
+
+impl DisableSudo {
+    fn council_seated() -> bool {
+        !pallet_collective::Members::<Runtime, CouncilCollective>::get().is_empty()
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn sudo_key() -> Option<AccountId> {
+        frame_support::storage::migration::get_storage_value(Sudo::name().as_bytes(), b"Key", &[])
+    }
+}
+
+impl OnRuntimeUpgrade for DisableSudo {
+    fn on_runtime_upgrade() -> Weight {
+        let db = <Runtime as frame_system::Config>::DbWeight::get();
+        if !Self::council_seated() {
+            log::info!(target: LOG_TARGET, "Council is not seated, keeping the sudo key");
+            return db.reads(1);
+        }
+        match take_storage_value::<AccountId>(Sudo::name().as_bytes(), b"Key", &[]) {
+            Some(key) => {
+                log::info!(target: LOG_TARGET, "Council is seated, removed sudo key {:?}", key);
+                db.reads_writes(2, 1)
+            }
+            None => db.reads(2),
+        }
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+        use codec::Encode;
+        Ok(Self::council_seated().encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+        use codec::Decode;
+        let council_seated =
+            bool::decode(&mut &state[..]).map_err(|_| "DisableSudo: invalid pre-upgrade state")?;
+        frame_support::ensure!(
+            !council_seated || Self::sudo_key().is_none(),
+            "DisableSudo: sudo key survived while the council is seated"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,31 @@
+//! Weights for `cumulus_pallet_parachain_system`.
+//!
+//! These are conservative estimates until the runtime is benchmarked on reference hardware. To
+//! regenerate them, build the node with `--features runtime-benchmarks` and run:
+//!
+//! ```sh
+//! ./target/release/parachain-template-node benchmark pallet \
+//!     --pallet cumulus_pallet_parachain_system --extrinsic '*' \
+//!     --steps 50 --repeat 20 \
+//!     --output runtime/src/weights/cumulus_pallet_parachain_system.rs
+//! ```
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `cumulus_pallet_parachain_system`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> cumulus_pallet_parachain_system::WeightInfo for WeightInfo<T> {
+	/// The range of component `n` is `[0, 1000]`.
+	fn enqueue_inbound_downward_messages(n: u32) -> Weight {
+		Weight::from_parts(3_000_000, 12)
+			.saturating_add(Weight::from_parts(60_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+}
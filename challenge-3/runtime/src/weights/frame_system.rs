@@ -0,0 +1,72 @@
+//! Weights for `frame_system`.
+//!
+//! These are conservative estimates until the runtime is benchmarked on reference hardware. To
+//! regenerate them, build the node with `--features runtime-benchmarks` and run:
+//!
+//! ```sh
+//! ./target/release/parachain-template-node benchmark pallet \
+//!     --pallet frame_system --extrinsic '*' \
+//!     --steps 50 --repeat 20 \
+//!     --output runtime/src/weights/frame_system.rs
+//! ```
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `frame_system`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> frame_system::WeightInfo for WeightInfo<T> {
+	/// The range of component `b` is `[0, 3932160]`.
+	fn remark(b: u32) -> Weight {
+		Weight::from_parts(2_000_000, 0)
+			.saturating_add(Weight::from_parts(400, 0).saturating_mul(b.into()))
+	}
+	/// The range of component `b` is `[0, 3932160]`.
+	fn remark_with_event(b: u32) -> Weight {
+		Weight::from_parts(6_000_000, 0)
+			.saturating_add(Weight::from_parts(1_500, 0).saturating_mul(b.into()))
+	}
+	fn set_heap_pages() -> Weight {
+		Weight::from_parts(4_000_000, 1627)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn set_code() -> Weight {
+		Weight::from_parts(110_000_000_000, 1627)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// The range of component `i` is `[0, 1000]`.
+	fn set_storage(i: u32) -> Weight {
+		Weight::from_parts(2_500_000, 0)
+			.saturating_add(Weight::from_parts(1_000_000, 0).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(i.into())))
+	}
+	/// The range of component `i` is `[0, 1000]`.
+	fn kill_storage(i: u32) -> Weight {
+		Weight::from_parts(2_500_000, 0)
+			.saturating_add(Weight::from_parts(800_000, 0).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(i.into())))
+	}
+	/// The range of component `p` is `[0, 1000]`.
+	fn kill_prefix(p: u32) -> Weight {
+		Weight::from_parts(5_000_000, 80)
+			.saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+	}
+	fn authorize_upgrade() -> Weight {
+		Weight::from_parts(35_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn apply_authorized_upgrade() -> Weight {
+		Weight::from_parts(115_000_000_000, 1627)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+}
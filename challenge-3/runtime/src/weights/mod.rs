@@ -18,9 +18,26 @@
 //! Expose the auto generated weight files.
 
 pub mod block_weights;
+#[cfg(feature = "parachain")]
+pub mod cumulus_pallet_parachain_system;
+#[cfg(feature = "parachain")]
+pub mod cumulus_pallet_xcmp_queue;
 pub mod extrinsic_weights;
+pub mod frame_system;
+pub mod pallet_balances;
+#[cfg(feature = "parachain")]
+pub mod pallet_collator_selection;
+#[cfg(feature = "parachain")]
+pub mod pallet_message_queue;
+pub mod pallet_session;
+pub mod pallet_sudo;
+pub mod pallet_timestamp;
+#[cfg(feature = "parachain")]
+pub mod pallet_xcm;
 pub mod paritydb_weights;
 pub mod rocksdb_weights;
+#[cfg(feature = "parachain")]
+pub mod xcm;
 
 pub use block_weights::constants::BlockExecutionWeight;
 pub use extrinsic_weights::constants::ExtrinsicBaseWeight;
@@ -0,0 +1,75 @@
+//! Weights for `pallet_balances`.
+//!
+//! These are conservative estimates until the runtime is benchmarked on reference hardware. To
+//! regenerate them, build the node with `--features runtime-benchmarks` and run:
+//!
+//! ```sh
+//! ./target/release/parachain-template-node benchmark pallet \
+//!     --pallet pallet_balances --extrinsic '*' \
+//!     --steps 50 --repeat 20 \
+//!     --output runtime/src/weights/pallet_balances.rs
+//! ```
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_balances`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_balances::WeightInfo for WeightInfo<T> {
+	fn transfer_allow_death() -> Weight {
+		Weight::from_parts(60_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn transfer_keep_alive() -> Weight {
+		Weight::from_parts(47_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn force_set_balance_creating() -> Weight {
+		Weight::from_parts(18_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn force_set_balance_killing() -> Weight {
+		Weight::from_parts(25_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn force_transfer() -> Weight {
+		Weight::from_parts(62_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn transfer_all() -> Weight {
+		Weight::from_parts(58_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn force_unreserve() -> Weight {
+		Weight::from_parts(22_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// The range of component `u` is `[1, 1000]`.
+	fn upgrade_accounts(u: u32) -> Weight {
+		Weight::from_parts(21_000_000, 990)
+			.saturating_add(Weight::from_parts(17_000_000, 0).saturating_mul(u.into()))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(u.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(u.into())))
+	}
+	fn force_adjust_total_issuance() -> Weight {
+		Weight::from_parts(8_000_000, 0)
+	}
+	fn burn_allow_death() -> Weight {
+		Weight::from_parts(38_000_000, 0)
+	}
+	fn burn_keep_alive() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+	}
+}
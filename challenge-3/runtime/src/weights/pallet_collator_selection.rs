@@ -0,0 +1,108 @@
+//! Weights for `pallet_collator_selection`.
+//!
+//! These are conservative estimates until the runtime is benchmarked on reference hardware. To
+//! regenerate them, build the node with `--features runtime-benchmarks` and run:
+//!
+//! ```sh
+//! ./target/release/parachain-template-node benchmark pallet \
+//!     --pallet pallet_collator_selection --extrinsic '*' \
+//!     --steps 50 --repeat 20 \
+//!     --output runtime/src/weights/pallet_collator_selection.rs
+//! ```
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_collator_selection`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_collator_selection::WeightInfo for WeightInfo<T> {
+	/// The range of component `b` is `[1, 20]`.
+	fn set_invulnerables(b: u32) -> Weight {
+		Weight::from_parts(12_000_000, 1194)
+			.saturating_add(Weight::from_parts(3_500_000, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// The range of component `b` is `[1, 19]`.
+	/// The range of component `c` is `[1, 99]`.
+	fn add_invulnerable(b: u32, c: u32) -> Weight {
+		Weight::from_parts(45_000_000, 6287)
+			.saturating_add(Weight::from_parts(150_000, 0).saturating_mul(b.into()))
+			.saturating_add(Weight::from_parts(120_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// The range of component `b` is `[5, 20]`.
+	fn remove_invulnerable(b: u32) -> Weight {
+		Weight::from_parts(12_000_000, 6287)
+			.saturating_add(Weight::from_parts(120_000, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn set_desired_candidates() -> Weight {
+		Weight::from_parts(6_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// The range of component `c` is `[0, 100]`.
+	/// The range of component `k` is `[0, 100]`.
+	fn set_candidacy_bond(c: u32, k: u32) -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(250_000, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(250_000, 0).saturating_mul(k.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(k.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(k.into())))
+	}
+	/// The range of component `c` is `[5, 100]`.
+	fn update_bond(c: u32) -> Weight {
+		Weight::from_parts(35_000_000, 6287)
+			.saturating_add(Weight::from_parts(150_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// The range of component `c` is `[1, 99]`.
+	fn register_as_candidate(c: u32) -> Weight {
+		Weight::from_parts(48_000_000, 6287)
+			.saturating_add(Weight::from_parts(200_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// The range of component `c` is `[4, 100]`.
+	fn take_candidate_slot(c: u32) -> Weight {
+		Weight::from_parts(65_000_000, 6287)
+			.saturating_add(Weight::from_parts(220_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// The range of component `c` is `[4, 100]`.
+	fn leave_intent(c: u32) -> Weight {
+		Weight::from_parts(35_000_000, 6287)
+			.saturating_add(Weight::from_parts(180_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn note_author() -> Weight {
+		Weight::from_parts(45_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// The range of component `r` is `[1, 100]`.
+	/// The range of component `c` is `[1, 100]`.
+	fn new_session(r: u32, c: u32) -> Weight {
+		Weight::from_parts(20_000_000, 3593)
+			.saturating_add(Weight::from_parts(20_000, 0).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(15_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+	}
+}
@@ -0,0 +1,42 @@
+//! Weights for `pallet_sudo`.
+//!
+//! These are conservative estimates until the runtime is benchmarked on reference hardware. To
+//! regenerate them, build the node with `--features runtime-benchmarks` and run:
+//!
+//! ```sh
+//! ./target/release/parachain-template-node benchmark pallet \
+//!     --pallet pallet_sudo --extrinsic '*' \
+//!     --steps 50 --repeat 20 \
+//!     --output runtime/src/weights/pallet_sudo.rs
+//! ```
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_sudo`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_sudo::WeightInfo for WeightInfo<T> {
+	fn set_key() -> Weight {
+		Weight::from_parts(12_000_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo() -> Weight {
+		Weight::from_parts(13_000_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	fn sudo_as() -> Weight {
+		Weight::from_parts(13_000_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	fn remove_key() -> Weight {
+		Weight::from_parts(11_000_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
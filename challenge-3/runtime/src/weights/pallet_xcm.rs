@@ -0,0 +1,116 @@
+//! Weights for `pallet_xcm`.
+//!
+//! These are conservative estimates until the runtime is benchmarked on reference hardware. To
+//! regenerate them, build the node with `--features runtime-benchmarks` and run:
+//!
+//! ```sh
+//! ./target/release/parachain-template-node benchmark pallet \
+//!     --pallet pallet_xcm --extrinsic '*' \
+//!     --steps 50 --repeat 20 \
+//!     --output runtime/src/weights/pallet_xcm.rs
+//! ```
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_xcm`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_xcm::WeightInfo for WeightInfo<T> {
+	fn send() -> Weight {
+		Weight::from_parts(35_000_000, 3610)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn teleport_assets() -> Weight {
+		// Teleports are not supported by the runtime.
+		Weight::from_parts(18_446_744_073_709_551_000, 0)
+	}
+	fn reserve_transfer_assets() -> Weight {
+		Weight::from_parts(100_000_000, 3676)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn transfer_assets() -> Weight {
+		Weight::from_parts(100_000_000, 3676)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn execute() -> Weight {
+		Weight::from_parts(18_446_744_073_709_551_000, 0)
+	}
+	fn force_xcm_version() -> Weight {
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn force_default_xcm_version() -> Weight {
+		Weight::from_parts(4_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn force_subscribe_version_notify() -> Weight {
+		Weight::from_parts(40_000_000, 3610)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	fn force_unsubscribe_version_notify() -> Weight {
+		Weight::from_parts(42_000_000, 3827)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	fn force_suspension() -> Weight {
+		Weight::from_parts(4_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn migrate_supported_version() -> Weight {
+		Weight::from_parts(25_000_000, 15862)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	fn migrate_version_notifiers() -> Weight {
+		Weight::from_parts(25_000_000, 15866)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	fn already_notified_target() -> Weight {
+		Weight::from_parts(27_000_000, 18348)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+	}
+	fn notify_current_targets() -> Weight {
+		Weight::from_parts(38_000_000, 6152)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn notify_target_migration_fail() -> Weight {
+		Weight::from_parts(18_000_000, 13438)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+	}
+	fn migrate_version_notify_targets() -> Weight {
+		Weight::from_parts(26_000_000, 15873)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	fn migrate_and_notify_old_targets() -> Weight {
+		Weight::from_parts(50_000_000, 15879)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	fn new_query() -> Weight {
+		Weight::from_parts(3_000_000, 1485)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn take_response() -> Weight {
+		Weight::from_parts(20_000_000, 3862)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn claim_assets() -> Weight {
+		Weight::from_parts(40_000_000, 3610)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
@@ -0,0 +1,66 @@
+//! Weights for `pallet_xcm_benchmarks::fungible`.
+//!
+//! These are conservative estimates until the runtime is benchmarked on reference hardware. To
+//! regenerate them, build the node with `--features runtime-benchmarks` and run:
+//!
+//! ```sh
+//! ./target/release/parachain-template-node benchmark pallet \
+//!     --pallet pallet_xcm_benchmarks::fungible --extrinsic '*' \
+//!     --steps 50 --repeat 20 \
+//!     --template ./cumulus/templates/xcm-bench-template.hbs \
+//!     --output runtime/src/weights/xcm/pallet_xcm_benchmarks_fungible.rs
+//! ```
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weights for `pallet_xcm_benchmarks::fungible`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo<T> {
+	pub(crate) fn withdraw_asset() -> Weight {
+		Weight::from_parts(30_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	pub(crate) fn transfer_asset() -> Weight {
+		Weight::from_parts(45_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	pub(crate) fn transfer_reserve_asset() -> Weight {
+		Weight::from_parts(100_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	pub(crate) fn reserve_asset_deposited() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+	}
+	pub(crate) fn initiate_reserve_withdraw() -> Weight {
+		Weight::from_parts(70_000_000, 3610)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	pub(crate) fn receive_teleported_asset() -> Weight {
+		// Teleports are not supported by the runtime.
+		Weight::from_parts(18_446_744_073_709_551_000, 0)
+	}
+	pub(crate) fn deposit_asset() -> Weight {
+		Weight::from_parts(25_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	pub(crate) fn deposit_reserve_asset() -> Weight {
+		Weight::from_parts(80_000_000, 3610)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	pub(crate) fn initiate_teleport() -> Weight {
+		Weight::from_parts(70_000_000, 3610)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
@@ -0,0 +1,127 @@
+//! Weights for `pallet_xcm_benchmarks::generic`.
+//!
+//! These are conservative estimates until the runtime is benchmarked on reference hardware. To
+//! regenerate them, build the node with `--features runtime-benchmarks` and run:
+//!
+//! ```sh
+//! ./target/release/parachain-template-node benchmark pallet \
+//!     --pallet pallet_xcm_benchmarks::generic --extrinsic '*' \
+//!     --steps 50 --repeat 20 \
+//!     --template ./cumulus/templates/xcm-bench-template.hbs \
+//!     --output runtime/src/weights/xcm/pallet_xcm_benchmarks_generic.rs
+//! ```
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weights for `pallet_xcm_benchmarks::generic`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo<T> {
+	pub(crate) fn report_holding() -> Weight {
+		Weight::from_parts(70_000_000, 3610)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	pub(crate) fn buy_execution() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+	}
+	pub(crate) fn query_response() -> Weight {
+		Weight::from_parts(12_000_000, 3568)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	pub(crate) fn transact() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+	}
+	pub(crate) fn refund_surplus() -> Weight {
+		Weight::from_parts(3_000_000, 0)
+	}
+	pub(crate) fn set_error_handler() -> Weight {
+		Weight::from_parts(1_000_000, 0)
+	}
+	pub(crate) fn set_appendix() -> Weight {
+		Weight::from_parts(1_000_000, 0)
+	}
+	pub(crate) fn clear_error() -> Weight {
+		Weight::from_parts(1_000_000, 0)
+	}
+	pub(crate) fn descend_origin() -> Weight {
+		Weight::from_parts(1_500_000, 0)
+	}
+	pub(crate) fn clear_origin() -> Weight {
+		Weight::from_parts(1_000_000, 0)
+	}
+	pub(crate) fn report_error() -> Weight {
+		Weight::from_parts(50_000_000, 3610)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	pub(crate) fn claim_asset() -> Weight {
+		Weight::from_parts(15_000_000, 3488)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	pub(crate) fn trap() -> Weight {
+		Weight::from_parts(1_000_000, 0)
+	}
+	pub(crate) fn subscribe_version() -> Weight {
+		Weight::from_parts(40_000_000, 3610)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	pub(crate) fn unsubscribe_version() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	pub(crate) fn burn_asset() -> Weight {
+		Weight::from_parts(2_000_000, 0)
+	}
+	pub(crate) fn expect_asset() -> Weight {
+		Weight::from_parts(1_500_000, 0)
+	}
+	pub(crate) fn expect_origin() -> Weight {
+		Weight::from_parts(1_000_000, 0)
+	}
+	pub(crate) fn expect_error() -> Weight {
+		Weight::from_parts(1_000_000, 0)
+	}
+	pub(crate) fn expect_transact_status() -> Weight {
+		Weight::from_parts(1_500_000, 0)
+	}
+	pub(crate) fn query_pallet() -> Weight {
+		Weight::from_parts(55_000_000, 3610)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	pub(crate) fn expect_pallet() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+	}
+	pub(crate) fn report_transact_status() -> Weight {
+		Weight::from_parts(50_000_000, 3610)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	pub(crate) fn clear_transact_status() -> Weight {
+		Weight::from_parts(1_000_000, 0)
+	}
+	pub(crate) fn set_topic() -> Weight {
+		Weight::from_parts(1_000_000, 0)
+	}
+	pub(crate) fn clear_topic() -> Weight {
+		Weight::from_parts(1_000_000, 0)
+	}
+	pub(crate) fn set_fees_mode() -> Weight {
+		Weight::from_parts(1_000_000, 0)
+	}
+	pub(crate) fn unpaid_execution() -> Weight {
+		Weight::from_parts(1_500_000, 0)
+	}
+	pub(crate) fn universal_origin() -> Weight {
+		// No universal aliases are configured.
+		Weight::from_parts(18_446_744_073_709_551_000, 0)
+	}
+}
@@ -0,0 +1,197 @@
+//! Transaction fees paid in an asset, swapped to the native token through its
+//! `pallet_asset_conversion` pool.
+
+#[cfg(feature = "solochain")]
+extern crate parachain_from_scratch_solochain_runtime as parachain_from_scratch_runtime;
+
+use frame_support::{
+    assert_ok,
+    dispatch::{GetDispatchInfo, PostDispatchInfo},
+    traits::{fungible::NativeOrWithId, fungibles::Inspect},
+};
+use pallet_asset_conversion_tx_payment::ChargeAssetTxPayment;
+use parachain_from_scratch_runtime::{
+    AccountId, AssetConversion, Balance, Balances, BalancesConfig, NativeOrAssetId, Runtime,
+    RuntimeCall, RuntimeEvent, RuntimeGenesisConfig, RuntimeOrigin, System, TransactionPayment,
+    EXISTENTIAL_DEPOSIT, UNIT,
+};
+use sp_keyring::Sr25519Keyring;
+use sp_runtime::{traits::SignedExtension, BuildStorage};
+
+/// The native token and the assets fees can be paid in.
+type NativeAndAssets = <Runtime as pallet_asset_conversion::Config>::Assets;
+
+/// The assets fees can be paid in, besides the native token.
+type AssetId = <<Runtime as pallet_asset_conversion_tx_payment::Config>::Fungibles as Inspect<
+    AccountId,
+>>::AssetId;
+
+/// Encoded length of a signed transfer.
+const TRANSFER_LEN: usize = 150;
+
+/// A local stablecoin.
+#[cfg(feature = "solochain")]
+fn usd_id() -> u32 {
+    1984
+}
+
+/// USDT, reserve transferred from Asset Hub.
+#[cfg(feature = "parachain")]
+fn usd_id() -> xcm::latest::Location {
+    use xcm::latest::{Junction::*, Location};
+    Location::new(1, [Parachain(1000), PalletInstance(50), GeneralIndex(1984)])
+}
+
+fn usd() -> NativeOrAssetId {
+    NativeOrWithId::WithId(usd_id())
+}
+
+fn alice() -> AccountId {
+    Sr25519Keyring::Alice.to_account_id()
+}
+
+fn bob() -> AccountId {
+    Sr25519Keyring::Bob.to_account_id()
+}
+
+fn treasury() -> AccountId {
+    pallet_treasury::Pallet::<Runtime>::account_id()
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+    // Sufficient, so that Bob's account exists without any native token.
+    let assets = vec![(usd_id(), alice(), true, 1)];
+    let accounts = vec![
+        (usd_id(), alice(), 1_000_000 * UNIT),
+        (usd_id(), bob(), 1_000 * UNIT),
+    ];
+
+    let mut ext: sp_io::TestExternalities = RuntimeGenesisConfig {
+        balances: BalancesConfig {
+            balances: vec![(alice(), 1_000_000 * UNIT), (treasury(), UNIT)],
+        },
+        #[cfg(feature = "solochain")]
+        assets: parachain_from_scratch_runtime::AssetsConfig {
+            assets,
+            accounts,
+            ..Default::default()
+        },
+        #[cfg(feature = "parachain")]
+        foreign_assets: parachain_from_scratch_runtime::ForeignAssetsConfig {
+            assets,
+            accounts,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+    .build_storage()
+    .unwrap()
+    .into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+/// Opens a native/USD pool at one to one.
+fn create_pool() {
+    assert_ok!(AssetConversion::create_pool(
+        RuntimeOrigin::signed(alice()),
+        Box::new(NativeOrWithId::Native),
+        Box::new(usd()),
+    ));
+    assert_ok!(AssetConversion::add_liquidity(
+        RuntimeOrigin::signed(alice()),
+        Box::new(NativeOrWithId::Native),
+        Box::new(usd()),
+        10_000 * UNIT,
+        10_000 * UNIT,
+        1,
+        1,
+        alice(),
+    ));
+}
+
+fn transfer() -> RuntimeCall {
+    RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+        dest: alice().into(),
+        value: UNIT,
+    })
+}
+
+/// Charges the fee of `call` to `who` in `asset`, the way `Executive` does around its dispatch.
+fn charge_fee(who: AccountId, call: &RuntimeCall, asset: Option<AssetId>) {
+    let info = call.get_dispatch_info();
+    let pre = ChargeAssetTxPayment::<Runtime>::from(0, asset)
+        .pre_dispatch(&who, call, &info, TRANSFER_LEN)
+        .expect("the fee can be paid");
+    assert_ok!(ChargeAssetTxPayment::<Runtime>::post_dispatch(
+        Some(pre),
+        &info,
+        &PostDispatchInfo::default(),
+        TRANSFER_LEN,
+        &Ok(()),
+    ));
+}
+
+fn native_fee(call: &RuntimeCall) -> Balance {
+    TransactionPayment::compute_fee(TRANSFER_LEN as u32, &call.get_dispatch_info(), 0)
+}
+
+#[test]
+fn fees_are_paid_in_an_asset_with_a_pool() {
+    new_test_ext().execute_with(|| {
+        create_pool();
+        let call = transfer();
+        let fee = native_fee(&call);
+        let quote = AssetConversion::quote_price_tokens_for_exact_tokens(
+            usd(),
+            NativeOrWithId::Native,
+            fee,
+            true,
+        )
+        .expect("the pool has liquidity");
+        let treasury_before = Balances::free_balance(treasury());
+
+        charge_fee(bob(), &call, Some(usd_id()));
+
+        // Bob has no native token, so the existential deposit is swapped in along with the fee
+        // and back once the fee is charged, at the cost of the pool fee on the round trip.
+        let paid = System::events()
+            .into_iter()
+            .find_map(|record| match record.event {
+                RuntimeEvent::AssetTxPayment(
+                    pallet_asset_conversion_tx_payment::Event::AssetTxFeePaid {
+                        actual_fee, ..
+                    },
+                ) => Some(actual_fee),
+                _ => None,
+            })
+            .expect("the fee was paid in USD");
+        assert_eq!(NativeAndAssets::balance(usd(), &bob()), 1_000 * UNIT - paid);
+        assert!(paid >= quote && paid - quote < EXISTENTIAL_DEPOSIT / 100);
+        assert_eq!(Balances::free_balance(bob()), 0);
+        assert_eq!(Balances::free_balance(treasury()), treasury_before + fee);
+    });
+}
+
+#[test]
+fn fees_cannot_be_paid_in_an_asset_without_a_pool() {
+    new_test_ext().execute_with(|| {
+        let call = transfer();
+
+        assert!(ChargeAssetTxPayment::<Runtime>::from(0, Some(usd_id()))
+            .pre_dispatch(&bob(), &call, &call.get_dispatch_info(), TRANSFER_LEN)
+            .is_err());
+    });
+}
+
+#[test]
+fn fees_are_paid_in_the_native_token_by_default() {
+    new_test_ext().execute_with(|| {
+        let call = transfer();
+        let fee = native_fee(&call);
+
+        charge_fee(alice(), &call, None);
+
+        assert_eq!(Balances::free_balance(alice()), 1_000_000 * UNIT - fee);
+    });
+}
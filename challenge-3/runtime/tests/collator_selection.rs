@@ -0,0 +1,182 @@
+//! Permissionless collator candidacy with `pallet_collator_selection`.
+//!
+//! Alice and Bob are invulnerable collators. The other dev accounts register session keys and bond
+//! to become candidates, and blocks are authored through Aura pre-runtime digests, so authorship is
+//! tracked by `pallet_authorship` and sessions are rotated by `pallet_session` like on a live chain.
+
+#![cfg(feature = "parachain")]
+
+use codec::Encode;
+use frame_support::{assert_ok, traits::Hooks};
+use parachain_from_scratch_runtime::{
+    AccountId, AuraId, Authorship, Balance, Balances, BalancesConfig, CollatorSelection,
+    CollatorSelectionConfig, Runtime, RuntimeGenesisConfig, RuntimeOrigin, Session, SessionConfig,
+    SessionKeys, System, EXISTENTIAL_DEPOSIT, UNIT,
+};
+use sp_consensus_aura::{Slot, AURA_ENGINE_ID};
+use sp_keyring::Sr25519Keyring::{self, Alice, Bob, Charlie, Dave, Eve};
+use sp_runtime::{BuildStorage, Digest, DigestItem};
+
+const CANDIDACY_BOND: Balance = 10 * UNIT;
+
+fn session_keys(key: Sr25519Keyring) -> SessionKeys {
+    SessionKeys {
+        aura: AuraId::from(key.public()),
+    }
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+    let invulnerables = [Alice, Bob];
+    let mut ext: sp_io::TestExternalities = RuntimeGenesisConfig {
+        balances: BalancesConfig {
+            balances: Sr25519Keyring::iter()
+                .map(|key| (key.to_account_id(), 1_000 * UNIT))
+                .collect(),
+        },
+        collator_selection: CollatorSelectionConfig {
+            invulnerables: invulnerables
+                .iter()
+                .map(|key| key.to_account_id())
+                .collect(),
+            candidacy_bond: CANDIDACY_BOND,
+            desired_candidates: 20,
+        },
+        session: SessionConfig {
+            keys: invulnerables
+                .iter()
+                .map(|key| (key.to_account_id(), key.to_account_id(), session_keys(*key)))
+                .collect(),
+        },
+        ..Default::default()
+    }
+    .build_storage()
+    .unwrap()
+    .into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+/// Registers the session keys of `key` and bonds it as a collator candidate.
+fn become_candidate(key: Sr25519Keyring) {
+    let who = key.to_account_id();
+    assert_ok!(Session::set_keys(
+        RuntimeOrigin::signed(who.clone()),
+        session_keys(key),
+        vec![]
+    ));
+    assert_ok!(CollatorSelection::register_as_candidate(
+        RuntimeOrigin::signed(who)
+    ));
+}
+
+fn candidates() -> Vec<AccountId> {
+    pallet_collator_selection::CandidateList::<Runtime>::get()
+        .into_iter()
+        .map(|candidate| candidate.who)
+        .collect()
+}
+
+/// Runs the hooks of the next block, authored by `author`.
+fn run_block(author: Sr25519Keyring) {
+    let number = System::block_number() + 1;
+    // Aura gives a slot to the authority at the slot number modulo the number of authorities.
+    let slot = Session::validators()
+        .iter()
+        .position(|collator| *collator == author.to_account_id())
+        .expect("only collators author blocks");
+    let digest = Digest {
+        logs: vec![DigestItem::PreRuntime(
+            AURA_ENGINE_ID,
+            Slot::from(slot as u64).encode(),
+        )],
+    };
+    System::initialize(&number, &System::parent_hash(), &digest);
+    Authorship::on_initialize(number);
+    Session::on_initialize(number);
+    Authorship::on_finalize(number);
+}
+
+/// Runs blocks authored in turn by `authors` up to the start of the next session.
+fn run_to_next_session(authors: &[Sr25519Keyring]) {
+    let session = Session::current_index();
+    for author in authors.iter().cycle() {
+        run_block(*author);
+        if Session::current_index() > session {
+            break;
+        }
+    }
+}
+
+#[test]
+fn candidates_collate_from_the_session_after_next() {
+    new_test_ext().execute_with(|| {
+        let charlie = Charlie.to_account_id();
+
+        become_candidate(Charlie);
+        assert_eq!(candidates(), vec![charlie.clone()]);
+        assert_eq!(Balances::reserved_balance(&charlie), CANDIDACY_BOND);
+
+        // The collators of the next session were queued at the end of the previous one.
+        run_to_next_session(&[Alice, Bob]);
+        assert!(!Session::validators().contains(&charlie));
+        assert!(Session::queued_keys()
+            .iter()
+            .any(|(who, _)| *who == charlie));
+
+        run_to_next_session(&[Alice, Bob]);
+        assert!(Session::validators().contains(&charlie));
+        assert!(pallet_aura::Authorities::<Runtime>::get().contains(&session_keys(Charlie).aura));
+        run_block(Charlie);
+        assert_eq!(
+            pallet_collator_selection::LastAuthoredBlock::<Runtime>::get(&charlie),
+            System::block_number()
+        );
+    });
+}
+
+#[test]
+fn block_authors_are_paid_from_the_pot() {
+    new_test_ext().execute_with(|| {
+        let alice = Alice.to_account_id();
+        let pot = CollatorSelection::account_id();
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(Dave.to_account_id()),
+            pot.clone().into(),
+            100 * UNIT,
+        ));
+        let reward = (Balances::free_balance(&pot) - EXISTENTIAL_DEPOSIT) / 2;
+        let before = Balances::free_balance(&alice);
+
+        run_block(Alice);
+
+        assert_eq!(Balances::free_balance(&alice), before + reward);
+        assert_eq!(Balances::free_balance(&pot), reward + EXISTENTIAL_DEPOSIT);
+    });
+}
+
+#[test]
+fn candidates_missing_blocks_are_kicked() {
+    new_test_ext().execute_with(|| {
+        let eve = Eve.to_account_id();
+        // Candidates are only kicked while there are more than `MinEligibleCollators`.
+        for key in [Charlie, Dave, Eve] {
+            become_candidate(key);
+        }
+
+        run_to_next_session(&[Alice, Bob]);
+        run_to_next_session(&[Alice, Bob]);
+        assert!(Session::validators().contains(&eve));
+
+        // Eve gets slots but never authors a block. Registration counts as authoring a block
+        // `KickThreshold` blocks later, so this is the first session change where Eve is stale.
+        run_to_next_session(&[Alice, Bob, Charlie, Dave]);
+        assert_eq!(candidates().len(), 2);
+        assert!(!candidates().contains(&eve));
+        assert_eq!(Balances::reserved_balance(&eve), 0);
+
+        // Eve was already queued for the session that just started, and is gone from the next.
+        assert!(Session::validators().contains(&eve));
+        run_to_next_session(&[Alice, Bob, Charlie, Dave]);
+        assert!(!Session::validators().contains(&eve));
+    });
+}
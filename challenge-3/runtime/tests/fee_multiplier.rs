@@ -0,0 +1,200 @@
+//! Response of transaction fees to congestion.
+//!
+//! The fee multiplier is updated at the end of every block from how full the `Normal` dispatch
+//! class was, which `NORMAL_DISPATCH_RATIO` caps at a share of the block. These tests replay days
+//! of blocks filled to a fixed share of that limit and follow the multiplier and the fee of a
+//! transfer.
+//!
+//! Run them with `--nocapture` to see the fee curve:
+//!
+//! ```sh
+//! cargo test -p parachain-from-scratch-runtime --test fee_multiplier -- --nocapture
+//! ```
+
+#[cfg(feature = "solochain")]
+extern crate parachain_from_scratch_solochain_runtime as parachain_from_scratch_runtime;
+
+use frame_support::{
+    dispatch::{DispatchClass, GetDispatchInfo},
+    traits::Hooks,
+    weights::Weight,
+};
+use pallet_transaction_payment::{Multiplier, NextFeeMultiplier};
+use parachain_from_scratch_runtime::{
+    Balance, Perbill, Runtime, RuntimeCall, RuntimeGenesisConfig, System, TransactionPayment, DAYS,
+    HOURS, MILLIUNIT,
+};
+use sp_runtime::{traits::One, AccountId32, BuildStorage, FixedPointNumber};
+
+/// Encoded length of a signed transfer.
+const TRANSFER_LEN: u32 = 150;
+
+/// Fullness of the `Normal` dispatch class, in percent, of the simulated blocks.
+const FULLNESS: [u32; 6] = [0, 10, 25, 50, 75, 100];
+
+fn new_test_ext() -> sp_io::TestExternalities {
+    let mut ext: sp_io::TestExternalities = RuntimeGenesisConfig::default()
+        .build_storage()
+        .unwrap()
+        .into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+/// Weight of a `Normal` class filled to `fullness` of its limit.
+fn normal_weight(fullness: Perbill) -> Weight {
+    let max = <Runtime as frame_system::Config>::BlockWeights::get()
+        .get(DispatchClass::Normal)
+        .max_total
+        .expect("the normal class is bounded");
+    Weight::from_parts(
+        fullness.mul_floor(max.ref_time()),
+        fullness.mul_floor(max.proof_size()),
+    )
+}
+
+/// Finalizes a block whose `Normal` class is filled to `fullness`, which updates the fee
+/// multiplier of the next block.
+fn run_block(fullness: Perbill) {
+    let now = System::block_number();
+    frame_system::BlockWeight::<Runtime>::mutate(|weight| {
+        weight.set(normal_weight(fullness), DispatchClass::Normal)
+    });
+    TransactionPayment::on_finalize(now);
+    frame_system::BlockWeight::<Runtime>::kill();
+    System::set_block_number(now + 1);
+}
+
+fn transfer_fee() -> Balance {
+    let call = RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+        dest: AccountId32::new([1u8; 32]).into(),
+        value: MILLIUNIT,
+    });
+    TransactionPayment::compute_fee(TRANSFER_LEN, &call.get_dispatch_info(), 0)
+}
+
+/// `MinimumMultiplier` of the runtime.
+fn minimum_multiplier() -> Multiplier {
+    Multiplier::saturating_from_rational(1, 10)
+}
+
+/// Asserts that `multiplier` is within 1% of `expected`.
+fn assert_multiplier_near(multiplier: Multiplier, expected: f64) {
+    let actual = multiplier.to_float();
+    assert!(
+        (actual - expected).abs() < expected / 100.0,
+        "multiplier {} is not within 1% of {}",
+        actual,
+        expected
+    );
+}
+
+/// The fee multiplier and transfer fee at every hour of a day of blocks filled to `fullness`.
+fn fee_curve(fullness: Perbill) -> Vec<(Multiplier, Balance)> {
+    new_test_ext().execute_with(|| {
+        let mut curve = vec![(NextFeeMultiplier::<Runtime>::get(), transfer_fee())];
+        for _ in 0..DAYS / HOURS {
+            for _ in 0..HOURS {
+                run_block(fullness);
+            }
+            curve.push((NextFeeMultiplier::<Runtime>::get(), transfer_fee()));
+        }
+        curve
+    })
+}
+
+#[test]
+fn fees_follow_congestion() {
+    let curves: Vec<_> = FULLNESS
+        .iter()
+        .map(|&percent| fee_curve(Perbill::from_percent(percent)))
+        .collect();
+
+    println!("transfer fee in MILLIUNIT (multiplier) by hour and normal class fullness");
+    print!("{:>4}", "hour");
+    for percent in FULLNESS {
+        print!(" {:>22}", format!("{}%", percent));
+    }
+    println!();
+    for hour in 0..curves[0].len() {
+        print!("{:>4}", hour);
+        for curve in &curves {
+            let (multiplier, fee) = curve[hour];
+            print!(
+                " {:>22}",
+                format!(
+                    "{:.4} ({:.4})",
+                    fee as f64 / MILLIUNIT as f64,
+                    multiplier.to_float()
+                )
+            );
+        }
+        println!();
+    }
+
+    let after_a_day = |percent| {
+        let index = FULLNESS.iter().position(|&p| p == percent).unwrap();
+        *curves[index].last().unwrap()
+    };
+    let (initial_multiplier, initial_fee) = curves[0][0];
+    assert_eq!(initial_multiplier, Multiplier::one());
+
+    // Quiet blocks make transactions cheaper, busy ones more expensive.
+    assert!(after_a_day(0).1 < initial_fee);
+    assert!(after_a_day(10).1 < initial_fee);
+    assert!(after_a_day(50).1 > initial_fee);
+    // Blocks at the target fullness keep the fee where it is.
+    assert_multiplier_near(after_a_day(25).0, 1.0);
+    // A day of empty blocks takes a quarter off the multiplier, while a day of full blocks a bit
+    // more than doubles it. The adjustment is slow on purpose: sustained congestion is priced in
+    // over days, not hours.
+    assert_multiplier_near(after_a_day(0).0, 0.763);
+    assert_multiplier_near(after_a_day(50).0, 1.31);
+    assert_multiplier_near(after_a_day(100).0, 2.25);
+    // Fees rise with congestion.
+    for pair in curves.windows(2) {
+        assert!(pair[0].last().unwrap().1 <= pair[1].last().unwrap().1);
+    }
+}
+
+#[test]
+fn quiet_week_stays_above_the_minimum() {
+    new_test_ext().execute_with(|| {
+        for _ in 0..7 * DAYS {
+            run_block(Perbill::zero());
+        }
+        let after_a_week = NextFeeMultiplier::<Runtime>::get();
+        assert_multiplier_near(after_a_week, 0.151);
+        assert!(after_a_week > minimum_multiplier());
+
+        // The multiplier keeps falling, it has not reached its floor yet.
+        run_block(Perbill::zero());
+        assert!(NextFeeMultiplier::<Runtime>::get() < after_a_week);
+    });
+}
+
+#[test]
+fn multiplier_recovers_from_its_minimum() {
+    new_test_ext().execute_with(|| {
+        NextFeeMultiplier::<Runtime>::put(minimum_multiplier());
+        run_block(Perbill::zero());
+        assert_eq!(NextFeeMultiplier::<Runtime>::get(), minimum_multiplier());
+
+        // Congestion moves it up from there, but slowly: a day of full blocks only gets it to
+        // 0.22, and it takes three to get back to normal.
+        run_block(Perbill::one());
+        assert!(NextFeeMultiplier::<Runtime>::get() > minimum_multiplier());
+        for _ in 1..DAYS {
+            run_block(Perbill::one());
+        }
+        assert_multiplier_near(NextFeeMultiplier::<Runtime>::get(), 0.225);
+        for _ in 0..DAYS {
+            run_block(Perbill::one());
+        }
+        assert!(NextFeeMultiplier::<Runtime>::get() < Multiplier::one());
+        for _ in 0..DAYS {
+            run_block(Perbill::one());
+        }
+        assert!(NextFeeMultiplier::<Runtime>::get() > Multiplier::one());
+    });
+}
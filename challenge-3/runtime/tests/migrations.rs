@@ -0,0 +1,119 @@
+//! Runtime upgrade migrations, run against a snapshot of the dev genesis.
+//!
+//! With `--features try-runtime` the `pre_upgrade` and `post_upgrade` checks of every migration
+//! run as well:
+//!
+//! ```sh
+//! cargo test -p parachain-from-scratch-runtime --features try-runtime --test migrations
+//! ```
+//!
+//! The same tests run against the solochain flavour through its package.
+
+#[cfg(feature = "solochain")]
+extern crate parachain_from_scratch_solochain_runtime as parachain_from_scratch_runtime;
+
+use frame_support::{
+    assert_ok,
+    storage::{migration::get_storage_value, storage_prefix, unhashed},
+    traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+use pallet_team_registry::Profiles;
+use parachain_from_scratch_runtime::{
+    AccountId, BalancesConfig, Migrations, Runtime, RuntimeGenesisConfig, RuntimeOrigin,
+    SudoConfig, System, TeamRegistry, UNIT,
+};
+use sp_keyring::Sr25519Keyring;
+use sp_runtime::BuildStorage;
+
+fn alice() -> AccountId {
+    Sr25519Keyring::Alice.to_account_id()
+}
+
+fn sudo_key() -> Option<AccountId> {
+    get_storage_value(b"Sudo", b"Key", &[])
+}
+
+/// The state of a dev chain: funded dev accounts, with Alice as the sudo key.
+fn dev_genesis() -> sp_io::TestExternalities {
+    let mut ext: sp_io::TestExternalities = RuntimeGenesisConfig {
+        balances: BalancesConfig {
+            balances: Sr25519Keyring::iter()
+                .map(|key| (key.to_account_id(), 1_000_000 * UNIT))
+                .collect(),
+        },
+        sudo: SudoConfig { key: Some(alice()) },
+        ..Default::default()
+    }
+    .build_storage()
+    .unwrap()
+    .into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+fn run_migrations() {
+    #[cfg(feature = "try-runtime")]
+    assert_ok!(Migrations::try_on_runtime_upgrade(true));
+    #[cfg(not(feature = "try-runtime"))]
+    Migrations::on_runtime_upgrade();
+}
+
+#[test]
+fn migrations_are_noop_on_a_fresh_chain() {
+    dev_genesis().execute_with(|| {
+        run_migrations();
+
+        assert_eq!(
+            TeamRegistry::on_chain_storage_version(),
+            TeamRegistry::in_code_storage_version()
+        );
+        assert_eq!(sudo_key(), Some(alice()));
+    });
+}
+
+#[test]
+fn team_registry_profiles_are_counted() {
+    dev_genesis().execute_with(|| {
+        for key in [Sr25519Keyring::Alice, Sr25519Keyring::Bob] {
+            assert_ok!(TeamRegistry::set_profile(
+                RuntimeOrigin::signed(key.to_account_id()),
+                format!("{:?}", key).into_bytes(),
+                [0u8; 32],
+                Vec::new(),
+            ));
+        }
+        // Roll the pallet back to version 0, which had no profile counter.
+        unhashed::kill(&storage_prefix(b"TeamRegistry", b"CounterForProfiles"));
+        StorageVersion::new(0).put::<TeamRegistry>();
+        assert_eq!(Profiles::<Runtime>::count(), 0);
+
+        run_migrations();
+
+        assert_eq!(Profiles::<Runtime>::count(), 2);
+        assert_eq!(TeamRegistry::on_chain_storage_version(), 1);
+    });
+}
+
+#[test]
+fn sudo_is_disabled_once_the_council_is_seated() {
+    dev_genesis().execute_with(|| {
+        pallet_collective::Members::<Runtime, pallet_collective::Instance1>::put(vec![alice()]);
+
+        run_migrations();
+
+        assert_eq!(sudo_key(), None);
+    });
+}
+
+#[cfg(feature = "parachain")]
+#[test]
+fn xcm_migration_runs_with_a_default_xcm_version() {
+    dev_genesis().execute_with(|| {
+        assert_ok!(pallet_xcm::Pallet::<Runtime>::force_default_xcm_version(
+            RuntimeOrigin::root(),
+            Some(xcm::latest::VERSION),
+        ));
+
+        run_migrations();
+    });
+}
@@ -0,0 +1,175 @@
+//! PoV size accounting of a block full of balance transfers, with and without storage weight
+//! reclaim.
+//!
+//! Collators record a storage proof while building a block, and each extrinsic is charged the
+//! proof size of its benchmarked worst case up front. `StorageWeightReclaim` replaces that charge
+//! with the proof size actually recorded while applying the extrinsic. These tests fill a block
+//! with transfers the way `Executive` applies them and compare the proof size accounted in
+//! `BlockWeight` with the size of the recorded proof.
+//!
+//! Run them with `--nocapture` to see the numbers:
+//!
+//! ```sh
+//! cargo test -p parachain-from-scratch-runtime --test storage_weight_reclaim -- --nocapture
+//! ```
+
+#![cfg(feature = "parachain")]
+
+use cumulus_primitives_storage_weight_reclaim::StorageWeightReclaim;
+use frame_support::dispatch::GetDispatchInfo;
+use parachain_from_scratch_runtime::{
+    AccountId, BalancesConfig, Runtime, RuntimeCall, RuntimeGenesisConfig, RuntimeOrigin, System,
+    MILLIUNIT, UNIT,
+};
+use sp_core::Blake2Hasher;
+use sp_externalities::Extensions;
+use sp_runtime::{
+    traits::{Dispatchable, SignedExtension},
+    BuildStorage,
+};
+use sp_state_machine::{Ext, OverlayedChanges, TrieBackendBuilder};
+use sp_trie::{proof_size_extension::ProofSizeExt, recorder::Recorder};
+
+/// Number of accounts funded at genesis, more than the transfers a block can fit.
+const SENDERS: u32 = 10_000;
+
+/// Encoded length of a signed transfer.
+const TRANSFER_LEN: usize = 150;
+
+/// Proof size accounting of a block filled with transfers.
+#[derive(Debug)]
+struct FilledBlock {
+    /// Number of transfers that fit in the block.
+    transfers: u32,
+    /// Proof size accounted in `BlockWeight`.
+    accounted_proof_size: u64,
+    /// Size of the proof recorded while applying the transfers.
+    recorded_proof_size: u64,
+}
+
+fn sender(index: u32) -> AccountId {
+    account(index, 1)
+}
+
+fn recipient(index: u32) -> AccountId {
+    account(index, 2)
+}
+
+fn account(index: u32, tag: u8) -> AccountId {
+    let mut raw = [tag; 32];
+    raw[..4].copy_from_slice(&index.to_le_bytes());
+    raw.into()
+}
+
+/// Applies a transfer from sender `index` the way `Executive` applies a signed extrinsic, through
+/// the weight related signed extensions. Returns `false` once the block is full.
+fn apply_transfer(index: u32, reclaim: bool) -> bool {
+    let who = sender(index);
+    let call = RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+        dest: recipient(index).into(),
+        value: 10 * MILLIUNIT,
+    });
+    let info = call.get_dispatch_info();
+
+    if frame_system::CheckWeight::<Runtime>::new()
+        .pre_dispatch(&who, &call, &info, TRANSFER_LEN)
+        .is_err()
+    {
+        return false;
+    }
+    let reclaim_pre = reclaim.then(|| {
+        StorageWeightReclaim::<Runtime>::new()
+            .pre_dispatch(&who, &call, &info, TRANSFER_LEN)
+            .expect("reclaim never rejects a transaction")
+    });
+
+    let (post_info, result) = match call.dispatch(RuntimeOrigin::signed(who)) {
+        Ok(post_info) => (post_info, Ok(())),
+        Err(err) => (err.post_info, Err(err.error)),
+    };
+    assert_eq!(result, Ok(()));
+
+    frame_system::CheckWeight::<Runtime>::post_dispatch(
+        Some(()),
+        &info,
+        &post_info,
+        TRANSFER_LEN,
+        &result,
+    )
+    .unwrap();
+    if let Some(pre) = reclaim_pre {
+        StorageWeightReclaim::<Runtime>::post_dispatch(
+            Some(pre),
+            &info,
+            &post_info,
+            TRANSFER_LEN,
+            &result,
+        )
+        .unwrap();
+    }
+    true
+}
+
+/// Fills a block with transfers while recording a storage proof, as a collator does when building
+/// a block.
+fn fill_block(reclaim: bool) -> FilledBlock {
+    let storage = RuntimeGenesisConfig {
+        balances: BalancesConfig {
+            balances: (0..SENDERS).map(|i| (sender(i), UNIT)).collect(),
+        },
+        ..Default::default()
+    }
+    .build_storage()
+    .unwrap();
+    let backend = sp_io::TestExternalities::new(storage).as_backend();
+
+    let recorder = Recorder::<Blake2Hasher>::default();
+    let proving_backend = TrieBackendBuilder::wrap(&backend)
+        .with_recorder(recorder.clone())
+        .build();
+    let mut overlay = OverlayedChanges::default();
+    let mut extensions = Extensions::default();
+    extensions.register(ProofSizeExt::new(recorder.clone()));
+    let mut ext = Ext::new(&mut overlay, &proving_backend, Some(&mut extensions));
+
+    sp_externalities::set_and_run_with_externalities(&mut ext, || {
+        System::set_block_number(1);
+        // `BlockWeight` is read before the first extrinsic is measured, keep it out of the
+        // comparison.
+        frame_system::BlockWeight::<Runtime>::get();
+        let initial_proof_size = recorder.estimate_encoded_size() as u64;
+
+        let transfers = (0..SENDERS)
+            .take_while(|&i| apply_transfer(i, reclaim))
+            .count() as u32;
+        assert!(transfers < SENDERS, "the block never filled up");
+
+        FilledBlock {
+            transfers,
+            accounted_proof_size: frame_system::BlockWeight::<Runtime>::get()
+                .total()
+                .proof_size(),
+            recorded_proof_size: recorder.estimate_encoded_size() as u64 - initial_proof_size,
+        }
+    })
+}
+
+#[test]
+fn reclaim_fits_more_transfers_in_a_block() {
+    let benchmarked = fill_block(false);
+    let reclaimed = fill_block(true);
+
+    println!("without reclaim: {:?}", benchmarked);
+    println!("with reclaim:    {:?}", reclaimed);
+
+    // Without reclaim the block is full on paper long before the proof is.
+    assert!(benchmarked.accounted_proof_size > benchmarked.recorded_proof_size);
+    assert!(reclaimed.transfers > benchmarked.transfers);
+}
+
+#[test]
+fn reclaim_never_accounts_less_than_the_recorded_proof() {
+    let reclaimed = fill_block(true);
+
+    assert!(reclaimed.accounted_proof_size >= reclaimed.recorded_proof_size);
+}
@@ -1,8 +1,12 @@
-use parachain_from_scratch_runtime::{AccountId, Signature, WASM_BINARY};
+use std::path::Path;
+
+use parachain_from_scratch_runtime::{AccountId, Balance, Signature, WASM_BINARY};
+use sc_network::config::MultiaddrWithPeerId;
 use sc_service::ChainType;
+use serde::Deserialize;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_consensus_grandpa::AuthorityId as GrandpaId;
-use sp_core::{sr25519, Pair, Public};
+use sp_core::{crypto::Ss58Codec, sr25519, Pair, Public};
 use sp_runtime::traits::{IdentifyAccount, Verify};
 
 // The URL for the telemetry server.
@@ -11,6 +15,22 @@ use sp_runtime::traits::{IdentifyAccount, Verify};
 /// Specialized `ChainSpec`. This is a specialization of the general Substrate ChainSpec type.
 pub type ChainSpec = sc_service::GenericChainSpec;
 
+/// Seeds of the well-known development accounts, whose secret keys are public.
+const DEV_SEEDS: [&str; 12] = [
+    "Alice",
+    "Bob",
+    "Charlie",
+    "Dave",
+    "Eve",
+    "Ferdie",
+    "Alice//stash",
+    "Bob//stash",
+    "Charlie//stash",
+    "Dave//stash",
+    "Eve//stash",
+    "Ferdie//stash",
+];
+
 /// Generate a crypto pair from seed.
 pub fn get_from_seed<TPublic: Public>(seed: &str) -> <TPublic::Pair as Pair>::Public {
     TPublic::Pair::from_string(&format!("//{}", seed), None)
@@ -33,6 +53,32 @@ pub fn authority_keys_from_seed(s: &str) -> (AuraId, GrandpaId) {
     (get_from_seed::<AuraId>(s), get_from_seed::<GrandpaId>(s))
 }
 
+/// Whether `account` is one of the well-known development accounts.
+pub fn is_dev_account(account: &AccountId) -> bool {
+    DEV_SEEDS
+        .iter()
+        .any(|seed| get_account_id_from_seed::<sr25519::Public>(seed) == *account)
+}
+
+/// Whether `key` is the Aura key of one of the well-known development accounts.
+pub fn is_dev_aura_key(key: &AuraId) -> bool {
+    DEV_SEEDS
+        .iter()
+        .any(|seed| get_from_seed::<AuraId>(seed) == *key)
+}
+
+/// Whether `key` is the Grandpa key of one of the well-known development accounts.
+pub fn is_dev_grandpa_key(key: &GrandpaId) -> bool {
+    DEV_SEEDS
+        .iter()
+        .any(|seed| get_from_seed::<GrandpaId>(seed) == *key)
+}
+
+/// Whether chains of type `chain_type` may use development keys.
+pub fn is_dev_chain_type(chain_type: &ChainType) -> bool {
+    matches!(chain_type, ChainType::Development | ChainType::Local)
+}
+
 pub fn development_config() -> Result<ChainSpec, String> {
     Ok(ChainSpec::builder(
         WASM_BINARY.ok_or_else(|| "Development wasm not available".to_string())?,
@@ -118,3 +164,185 @@ fn testnet_genesis(
         },
     })
 }
+
+/// Configuration of a live chain, from which [`live_config`] builds its chain spec.
+///
+/// Keys and accounts are given in SS58 or as 32 hex encoded bytes, so that no secret ever needs to
+/// be known to build the chain spec.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct LiveChainConfig {
+    /// Name of the chain.
+    pub name: String,
+    /// Identifier of the chain, which also names its database directory.
+    pub id: String,
+    /// Type of the chain, `Live` by default.
+    #[serde(default = "live_chain_type")]
+    pub chain_type: ChainType,
+    /// Network protocol identifier.
+    pub protocol_id: Option<String>,
+    /// Symbol of the native token.
+    pub token_symbol: String,
+    /// Number of decimals of the native token.
+    pub token_decimals: u8,
+    /// SS58 prefix of the addresses of the chain, 42 by default.
+    #[serde(default = "generic_ss58_format")]
+    pub ss58_format: u16,
+    /// Boot nodes, as multiaddresses ending with `/p2p/<PEER_ID>`.
+    #[serde(default)]
+    pub boot_nodes: Vec<String>,
+    /// Block authors and finality voters of the chain.
+    pub authorities: Vec<LiveAuthority>,
+    /// Accounts endowed at genesis.
+    #[serde(default)]
+    pub endowments: Vec<LiveEndowment>,
+    /// Sudo key, if the chain has one.
+    pub sudo: Option<String>,
+    /// Initial members of the council.
+    #[serde(default)]
+    pub council: Vec<String>,
+}
+
+/// An authority of a live chain.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct LiveAuthority {
+    /// Aura key of the authority.
+    pub aura: String,
+    /// Grandpa key of the authority.
+    pub grandpa: String,
+}
+
+/// An account endowed at the genesis of a live chain.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct LiveEndowment {
+    /// The endowed account.
+    pub account: String,
+    /// Its free balance, in the smallest unit of the native token.
+    pub balance: Balance,
+}
+
+fn live_chain_type() -> ChainType {
+    ChainType::Live
+}
+
+fn generic_ss58_format() -> u16 {
+    42
+}
+
+/// Build the chain spec of a live or staging chain from the [`LiveChainConfig`] in `path`.
+///
+/// Development keys are refused unless the chain type is `Development` or `Local`.
+pub fn live_config(path: &Path) -> Result<ChainSpec, String> {
+    let file = std::fs::File::open(path)
+        .map_err(|e| format!("Error opening chain config `{}`: {}", path.display(), e))?;
+    let config: LiveChainConfig = serde_json::from_reader(file)
+        .map_err(|e| format!("Error parsing chain config `{}`: {}", path.display(), e))?;
+
+    let authorities = config
+        .authorities
+        .iter()
+        .map(|authority| Ok((parse_key(&authority.aura)?, parse_key(&authority.grandpa)?)))
+        .collect::<Result<Vec<(AuraId, GrandpaId)>, String>>()?;
+    let endowments = config
+        .endowments
+        .iter()
+        .map(|endowment| Ok((parse_key(&endowment.account)?, endowment.balance)))
+        .collect::<Result<Vec<(AccountId, Balance)>, String>>()?;
+    let sudo = config.sudo.as_deref().map(parse_key).transpose()?;
+    let council = config
+        .council
+        .iter()
+        .map(String::as_str)
+        .map(parse_key)
+        .collect::<Result<Vec<AccountId>, String>>()?;
+    let boot_nodes = config
+        .boot_nodes
+        .iter()
+        .map(|node| {
+            node.parse::<MultiaddrWithPeerId>()
+                .map_err(|e| format!("Invalid boot node `{}`: {}", node, e))
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    if authorities.is_empty() {
+        return Err("At least one authority is needed to produce blocks".into());
+    }
+    if sudo.is_none() && council.is_empty() {
+        return Err("Either a sudo key or council members are needed to govern the chain".into());
+    }
+    if !is_dev_chain_type(&config.chain_type) {
+        let mut accounts = endowments
+            .iter()
+            .map(|(account, _)| account)
+            .chain(sudo.iter())
+            .chain(council.iter());
+        if let Some(account) = accounts.find(|account| is_dev_account(account)) {
+            return Err(format!(
+                "{} is a development account, whose secret key is public",
+                account.to_ss58check()
+            ));
+        }
+        if let Some((aura, _)) = authorities
+            .iter()
+            .find(|(aura, grandpa)| is_dev_aura_key(aura) || is_dev_grandpa_key(grandpa))
+        {
+            return Err(format!(
+                "The authority {} uses development keys, whose secret keys are public",
+                aura.to_ss58check()
+            ));
+        }
+    }
+
+    let mut properties = sc_service::Properties::new();
+    properties.insert("tokenSymbol".into(), config.token_symbol.into());
+    properties.insert("tokenDecimals".into(), config.token_decimals.into());
+    properties.insert("ss58Format".into(), config.ss58_format.into());
+
+    let mut genesis = serde_json::json!({
+        "balances": {
+            "balances": endowments,
+        },
+        "aura": {
+            "authorities": authorities.iter().map(|x| x.0.clone()).collect::<Vec<_>>(),
+        },
+        "grandpa": {
+            "authorities": authorities.iter().map(|x| (x.1.clone(), 1)).collect::<Vec<_>>(),
+        },
+    });
+    if let Some(sudo) = sudo {
+        genesis["sudo"] = serde_json::json!({ "key": Some(sudo) });
+    }
+    if !council.is_empty() {
+        genesis["council"] = serde_json::json!({ "members": council });
+    }
+
+    let mut builder =
+        ChainSpec::builder(WASM_BINARY.ok_or("Development wasm not available")?, None)
+            .with_name(&config.name)
+            .with_id(&config.id)
+            .with_chain_type(config.chain_type)
+            .with_boot_nodes(boot_nodes)
+            .with_properties(properties)
+            .with_genesis_config_patch(genesis);
+    if let Some(protocol_id) = &config.protocol_id {
+        builder = builder.with_protocol_id(protocol_id);
+    }
+    Ok(builder.build())
+}
+
+/// Parse a key or an account ID given in SS58 or as hex encoded bytes.
+pub fn parse_key<T>(s: &str) -> Result<T, String>
+where
+    T: Ss58Codec + for<'a> TryFrom<&'a [u8]>,
+{
+    let key = if s.starts_with("0x") {
+        sp_core::bytes::from_hex(s)
+            .ok()
+            .and_then(|bytes| T::try_from(&bytes[..]).ok())
+    } else {
+        T::from_ss58check(s).ok()
+    };
+    key.ok_or_else(|| format!("Invalid key `{}`, expected SS58 or hex encoded bytes", s))
+}
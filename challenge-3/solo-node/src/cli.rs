@@ -7,6 +7,45 @@ pub struct Cli {
 
     #[clap(flatten)]
     pub run: RunCmd,
+
+    /// Seal blocks on demand instead of running Aura and Grandpa, for development and tests.
+    ///
+    /// `instant` seals a block as soon as a transaction enters the pool, `manual` only when asked
+    /// to through `engine_createBlock`, and `interval=<ms>` every given number of milliseconds.
+    /// Blocks are finalized as they are sealed, or through `engine_finalizeBlock`.
+    #[arg(long, value_name = "instant|manual|interval=<ms>")]
+    pub sealing: Option<Sealing>,
+}
+
+/// How blocks are sealed in development mode.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Sealing {
+    /// Seal a block as soon as a transaction enters the pool.
+    Instant,
+    /// Seal blocks through the `engine_createBlock` RPC only.
+    Manual,
+    /// Seal a block every given number of milliseconds.
+    Interval(u64),
+}
+
+impl std::str::FromStr for Sealing {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "instant" => Ok(Self::Instant),
+            "manual" => Ok(Self::Manual),
+            _ => match s.strip_prefix("interval=").map(str::parse) {
+                Some(Ok(millis)) if millis > 0 => Ok(Self::Interval(millis)),
+                Some(_) => Err(format!(
+                    "invalid sealing interval in `{s}`, expected milliseconds"
+                )),
+                None => Err(format!(
+                    "unknown sealing `{s}`, expected `instant`, `manual` or `interval=<ms>`"
+                )),
+            },
+        }
+    }
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -37,6 +76,9 @@ pub enum Subcommand {
     /// Revert the chain to a previous state.
     Revert(sc_cli::RevertCmd),
 
+    /// Check a chain specification for common mistakes, reporting all of them.
+    ValidateSpec(crate::spec_validation::ValidateSpecCmd),
+
     /// Sub-commands concerned with benchmarking.
     #[command(subcommand)]
     Benchmark(frame_benchmarking_cli::BenchmarkCmd),
@@ -1,11 +1,13 @@
 //! Substrate Node Template CLI library.
 #![warn(missing_docs)]
 
+mod benchmarking;
 mod chain_spec;
 mod cli;
 mod command;
 mod rpc;
 mod service;
+mod spec_validation;
 
 fn main() -> sc_cli::Result<()> {
     command::run()
@@ -1,14 +1,20 @@
 //! Service and ServiceFactory implementation. Specialized wrapper over substrate service.
 
-use futures::FutureExt;
+use crate::cli::Sealing;
+use codec::Decode;
+use futures::{channel::mpsc, FutureExt, StreamExt};
 use parachain_from_scratch_runtime::{self, apis::RuntimeApi, opaque::Block};
-use sc_client_api::{Backend, BlockBackend};
+use sc_client_api::{Backend, BlockBackend, StorageProvider};
 use sc_consensus_aura::{ImportQueueParams, SlotProportion, StartAuraParams};
 use sc_consensus_grandpa::SharedVoterState;
+use sc_consensus_manual_seal::{
+    consensus::aura::AuraConsensusDataProvider, EngineCommand, ManualSealParams,
+};
 use sc_service::{error::Error as ServiceError, Configuration, TaskManager, WarpSyncParams};
 use sc_telemetry::{Telemetry, TelemetryWorker};
-use sc_transaction_pool_api::OffchainTransactionPoolFactory;
+use sc_transaction_pool_api::{OffchainTransactionPoolFactory, TransactionPool};
 use sp_consensus_aura::sr25519::AuthorityPair as AuraPair;
+use sp_core::{storage::StorageKey, twox_128};
 use std::{sync::Arc, time::Duration};
 
 pub(crate) type FullClient = sc_service::TFullClient<
@@ -223,6 +229,7 @@ pub fn new_full<
                 client: client.clone(),
                 pool: pool.clone(),
                 deny_unsafe,
+                command_sink: None,
             };
             crate::rpc::create_full(deps).map_err(Into::into)
         })
@@ -343,3 +350,176 @@ pub fn new_full<
     network_starter.start_network();
     Ok(task_manager)
 }
+
+/// Builds a new service for a full client that seals blocks on demand instead of running Aura and
+/// Grandpa. Blocks are sealed by a single node and finalized as they are sealed, so this is only
+/// meant for development and tests.
+pub fn new_dev<N: sc_network::NetworkBackend<Block, <Block as sp_runtime::traits::Block>::Hash>>(
+    config: Configuration,
+    sealing: Sealing,
+) -> Result<TaskManager, ServiceError> {
+    let executor = sc_service::new_wasm_executor::<sp_io::SubstrateHostFunctions>(&config);
+    let (client, backend, keystore_container, mut task_manager) =
+        sc_service::new_full_parts::<Block, RuntimeApi, _>(&config, None, executor)?;
+    let client = Arc::new(client);
+
+    let select_chain = sc_consensus::LongestChain::new(backend.clone());
+
+    let transaction_pool = sc_transaction_pool::BasicPool::new_full(
+        config.transaction_pool.clone(),
+        config.role.is_authority().into(),
+        config.prometheus_registry(),
+        task_manager.spawn_essential_handle(),
+        client.clone(),
+    );
+
+    let import_queue = sc_consensus_manual_seal::import_queue(
+        Box::new(client.clone()),
+        &task_manager.spawn_essential_handle(),
+        config.prometheus_registry(),
+    );
+
+    let net_config = sc_network::config::FullNetworkConfiguration::<
+        Block,
+        <Block as sp_runtime::traits::Block>::Hash,
+        N,
+    >::new(&config.network);
+    let metrics = N::register_notification_metrics(config.prometheus_registry());
+
+    let (network, system_rpc_tx, tx_handler_controller, network_starter, sync_service) =
+        sc_service::build_network(sc_service::BuildNetworkParams {
+            config: &config,
+            net_config,
+            client: client.clone(),
+            transaction_pool: transaction_pool.clone(),
+            spawn_handle: task_manager.spawn_handle(),
+            import_queue,
+            block_announce_validator_builder: None,
+            warp_sync_params: None,
+            block_relay: None,
+            metrics,
+        })?;
+
+    let prometheus_registry = config.prometheus_registry().cloned();
+
+    // Requests from `engine_createBlock` and `engine_finalizeBlock`.
+    let (command_sink, rpc_commands) = mpsc::channel(1024);
+
+    let rpc_extensions_builder = {
+        let client = client.clone();
+        let pool = transaction_pool.clone();
+
+        Box::new(move |deny_unsafe, _| {
+            let deps = crate::rpc::FullDeps {
+                client: client.clone(),
+                pool: pool.clone(),
+                deny_unsafe,
+                command_sink: Some(command_sink.clone()),
+            };
+            crate::rpc::create_full(deps).map_err(Into::into)
+        })
+    };
+
+    let _rpc_handlers = sc_service::spawn_tasks(sc_service::SpawnTasksParams {
+        network: Arc::new(network.clone()),
+        client: client.clone(),
+        keystore: keystore_container.keystore(),
+        task_manager: &mut task_manager,
+        transaction_pool: transaction_pool.clone(),
+        rpc_builder: rpc_extensions_builder,
+        backend,
+        system_rpc_tx,
+        tx_handler_controller,
+        sync_service,
+        config,
+        telemetry: None,
+    })?;
+
+    let seal_new_block = |create_empty| EngineCommand::SealNewBlock {
+        create_empty,
+        finalize: true,
+        parent_hash: None,
+        sender: None,
+    };
+    let commands_stream = match sealing {
+        Sealing::Manual => rpc_commands.boxed(),
+        Sealing::Instant => futures::stream::select(
+            rpc_commands,
+            transaction_pool
+                .import_notification_stream()
+                .map(move |_| seal_new_block(false)),
+        )
+        .boxed(),
+        Sealing::Interval(millis) => futures::stream::select(
+            rpc_commands,
+            futures::stream::unfold((), move |()| async move {
+                futures_timer::Delay::new(Duration::from_millis(millis)).await;
+                Some((seal_new_block(true), ()))
+            }),
+        )
+        .boxed(),
+    };
+
+    let proposer_factory = sc_basic_authorship::ProposerFactory::new(
+        task_manager.spawn_handle(),
+        client.clone(),
+        transaction_pool.clone(),
+        prometheus_registry.as_ref(),
+        None,
+    );
+
+    let slot_duration = sc_consensus_aura::slot_duration(&*client)?;
+    let timestamp_client = client.clone();
+
+    let authorship_future = sc_consensus_manual_seal::run_manual_seal(ManualSealParams {
+        block_import: client.clone(),
+        env: proposer_factory,
+        client: client.clone(),
+        pool: transaction_pool,
+        commands_stream,
+        select_chain,
+        consensus_data_provider: Some(Box::new(AuraConsensusDataProvider::new(client))),
+        create_inherent_data_providers: move |parent_hash, ()| {
+            let client = timestamp_client.clone();
+            async move {
+                // Blocks can be sealed faster than the slot duration, while Aura requires every
+                // block to be in a later slot than its parent.
+                let timestamp = sp_timestamp::Timestamp::current()
+                    .as_millis()
+                    .max(parent_timestamp(&client, parent_hash) + slot_duration.as_millis());
+                let timestamp = sp_timestamp::InherentDataProvider::new(timestamp.into());
+
+                let slot =
+                    sp_consensus_aura::inherents::InherentDataProvider::from_timestamp_and_slot_duration(
+                        *timestamp,
+                        slot_duration,
+                    );
+
+                Ok((slot, timestamp))
+            }
+        },
+    });
+
+    // the sealing task is considered essential, i.e. if it fails we take down the service with
+    // it.
+    task_manager.spawn_essential_handle().spawn_blocking(
+        "manual-seal",
+        Some("block-authoring"),
+        authorship_future,
+    );
+
+    network_starter.start_network();
+    Ok(task_manager)
+}
+
+/// The timestamp of block `hash` in milliseconds, or zero for the genesis block.
+fn parent_timestamp(client: &FullClient, hash: <Block as sp_runtime::traits::Block>::Hash) -> u64 {
+    // `pallet_timestamp::Now`
+    let key = StorageKey([twox_128(b"Timestamp"), twox_128(b"Now")].concat());
+    client
+        .storage(hash, &key)
+        .ok()
+        .flatten()
+        .and_then(|now| u64::decode(&mut &now.0[..]).ok())
+        .unwrap_or_default()
+}
@@ -0,0 +1,152 @@
+//! Checks for the mistakes commonly made in a chain spec before launching a chain with it.
+//!
+//! The `validate-spec` command builds the genesis state of a chain spec through the
+//! `GenesisBuilder` of its runtime and inspects it, reporting every problem it finds at once.
+
+use codec::Decode;
+use parachain_from_scratch_runtime::{AccountId, Balance, Grandpa, Runtime, EXISTENTIAL_DEPOSIT};
+use sc_cli::{CliConfiguration, SharedParams};
+use sc_service::ChainSpec;
+use sp_core::{crypto::Ss58Codec, hashing::twox_128};
+
+use crate::chain_spec::{is_dev_account, is_dev_aura_key, is_dev_chain_type, is_dev_grandpa_key};
+
+/// The `validate-spec` command used to check a chain spec for common mistakes.
+#[derive(Debug, clap::Parser)]
+pub struct ValidateSpecCmd {
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub shared_params: SharedParams,
+}
+
+impl ValidateSpecCmd {
+    /// Run the `validate-spec` command.
+    pub fn run(&self, chain_spec: &dyn ChainSpec) -> sc_cli::Result<()> {
+        let problems = validate(chain_spec);
+        if problems.is_empty() {
+            println!("No problem found in chain spec `{}`", chain_spec.id());
+            return Ok(());
+        }
+        for problem in &problems {
+            eprintln!("- {}", problem);
+        }
+        Err(format!(
+            "Found {} problem(s) in chain spec `{}`",
+            problems.len(),
+            chain_spec.id()
+        )
+        .into())
+    }
+}
+
+impl CliConfiguration for ValidateSpecCmd {
+    fn shared_params(&self) -> &SharedParams {
+        &self.shared_params
+    }
+}
+
+/// Check `chain_spec`, returning a description of every problem found.
+pub fn validate(chain_spec: &dyn ChainSpec) -> Vec<String> {
+    let mut problems = Vec::new();
+    let dev_keys_allowed = is_dev_chain_type(&chain_spec.chain_type());
+
+    if !chain_spec.properties().contains_key("tokenSymbol") {
+        problems.push("The `tokenSymbol` property is missing".to_string());
+    }
+    // Building the genesis state panics on the first balance below the existential deposit, so
+    // the endowments of a plain chain spec are checked beforehand.
+    for (account, balance) in genesis_endowments(chain_spec) {
+        if balance < EXISTENTIAL_DEPOSIT {
+            problems.push(below_existential_deposit(&account, balance));
+        }
+    }
+
+    let storage = match chain_spec.as_storage_builder().build_storage() {
+        Ok(storage) => storage,
+        Err(e) => {
+            problems.push(format!("The genesis state cannot be built: {}", e));
+            return problems;
+        }
+    };
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        // A raw chain spec lists no endowments, its accounts are checked instead. Accounts that
+        // only exist through a sufficient asset hold no native token at all.
+        for (account, info) in frame_system::Account::<Runtime>::iter() {
+            if (1..EXISTENTIAL_DEPOSIT).contains(&info.data.free) {
+                problems.push(below_existential_deposit(&account, info.data.free));
+            }
+            if !dev_keys_allowed && is_dev_account(&account) {
+                problems.push(format!(
+                    "The development account {} is endowed on a {:?} chain",
+                    account.to_ss58check(),
+                    chain_spec.chain_type()
+                ));
+            }
+        }
+
+        if dev_keys_allowed {
+            return;
+        }
+        for aura in pallet_aura::Authorities::<Runtime>::get() {
+            if is_dev_aura_key(&aura) {
+                problems.push(format!(
+                    "The development Aura key {} is an authority key on a {:?} chain",
+                    aura.to_ss58check(),
+                    chain_spec.chain_type()
+                ));
+            }
+        }
+        for (grandpa, _) in Grandpa::grandpa_authorities() {
+            if is_dev_grandpa_key(&grandpa) {
+                problems.push(format!(
+                    "The development Grandpa key {} is an authority key on a {:?} chain",
+                    grandpa.to_ss58check(),
+                    chain_spec.chain_type()
+                ));
+            }
+        }
+        if let Some(sudo) = sudo_key().filter(is_dev_account) {
+            problems.push(format!(
+                "The sudo key is the development account {}, whose secret key is public",
+                sudo.to_ss58check()
+            ));
+        }
+    });
+
+    problems
+}
+
+/// The sudo key in the externalities, read directly since `pallet_sudo` keeps its storage private.
+fn sudo_key() -> Option<AccountId> {
+    let key = [twox_128(b"Sudo"), twox_128(b"Key")].concat();
+    sp_io::storage::get(&key).and_then(|raw| AccountId::decode(&mut &raw[..]).ok())
+}
+
+/// Endowments of the genesis config of a plain chain spec, or none for a raw chain spec.
+///
+/// Malformed endowments are left for the genesis build to report.
+fn genesis_endowments(chain_spec: &dyn ChainSpec) -> Vec<(AccountId, Balance)> {
+    let Some(json) = chain_spec
+        .as_json(false)
+        .ok()
+        .and_then(|json| serde_json::from_str::<serde_json::Value>(&json).ok())
+    else {
+        return Vec::new();
+    };
+    let genesis = &json["genesis"]["runtimeGenesis"];
+    let config = match genesis.get("patch") {
+        Some(patch) => patch,
+        None => &genesis["config"],
+    };
+    serde_json::from_value(config["balances"]["balances"].clone()).unwrap_or_default()
+}
+
+fn below_existential_deposit(account: &AccountId, balance: Balance) -> String {
+    format!(
+        "{} is endowed with {}, below the existential deposit of {}",
+        account.to_ss58check(),
+        balance,
+        EXISTENTIAL_DEPOSIT
+    )
+}